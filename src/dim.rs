@@ -0,0 +1,908 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::OnceLock;
+
+// ================= type-level integers =================
+// Integers are encoded as a chain of successors (positive) or predecessors (negative) of zero.
+// Succ only ever wraps non-negative integers and Pred only non-positive ones, so every integer
+// has exactly one representation and type equality coincides with numeric equality.
+
+pub trait Integer {
+    const VALUE: i32;
+}
+pub trait NonNegative: Integer {}
+pub trait NonPositive: Integer {}
+
+pub struct Z0;
+pub struct Succ<T>(PhantomData<T>);
+pub struct Pred<T>(PhantomData<T>);
+
+impl Integer for Z0 {
+    const VALUE: i32 = 0;
+}
+impl NonNegative for Z0 {}
+impl NonPositive for Z0 {}
+
+impl<T: NonNegative> Integer for Succ<T> {
+    const VALUE: i32 = T::VALUE + 1;
+}
+impl<T: NonNegative> NonNegative for Succ<T> {}
+
+impl<T: NonPositive> Integer for Pred<T> {
+    const VALUE: i32 = T::VALUE - 1;
+}
+impl<T: NonPositive> NonPositive for Pred<T> {}
+
+pub type P1 = Succ<Z0>;
+pub type P2 = Succ<P1>;
+pub type P3 = Succ<P2>;
+pub type P4 = Succ<P3>;
+pub type P5 = Succ<P4>;
+pub type P6 = Succ<P5>;
+pub type P7 = Succ<P6>;
+pub type P8 = Succ<P7>;
+pub type P9 = Succ<P8>;
+pub type N1 = Pred<Z0>;
+pub type N2 = Pred<N1>;
+pub type N3 = Pred<N2>;
+pub type N4 = Pred<N3>;
+pub type N5 = Pred<N4>;
+pub type N6 = Pred<N5>;
+pub type N7 = Pred<N6>;
+pub type N8 = Pred<N7>;
+pub type N9 = Pred<N8>;
+
+// ---------- arithmetic ----------
+pub trait Inc {
+    type Output: Integer;
+}
+impl Inc for Z0 {
+    type Output = P1;
+}
+impl<T: NonNegative> Inc for Succ<T> {
+    type Output = Succ<Succ<T>>;
+}
+impl<T: NonPositive> Inc for Pred<T> {
+    type Output = T;
+}
+
+pub trait Dec {
+    type Output: Integer;
+}
+impl Dec for Z0 {
+    type Output = N1;
+}
+impl<T: NonNegative> Dec for Succ<T> {
+    type Output = T;
+}
+impl<T: NonPositive> Dec for Pred<T> {
+    type Output = Pred<Pred<T>>;
+}
+
+pub trait Negate {
+    type Output: Integer;
+}
+impl Negate for Z0 {
+    type Output = Z0;
+}
+impl<T: NonNegative + Negate> Negate for Succ<T>
+where
+    T::Output: Dec,
+{
+    type Output = <T::Output as Dec>::Output;
+}
+impl<T: NonPositive + Negate> Negate for Pred<T>
+where
+    T::Output: Inc,
+{
+    type Output = <T::Output as Inc>::Output;
+}
+
+pub trait Plus<Rhs> {
+    type Output: Integer;
+}
+impl<B: Integer> Plus<B> for Z0 {
+    type Output = B;
+}
+impl<A: NonNegative + Plus<B>, B> Plus<B> for Succ<A>
+where
+    A::Output: Inc,
+{
+    type Output = <A::Output as Inc>::Output;
+}
+impl<A: NonPositive + Plus<B>, B> Plus<B> for Pred<A>
+where
+    A::Output: Dec,
+{
+    type Output = <A::Output as Dec>::Output;
+}
+
+pub trait Minus<Rhs> {
+    type Output: Integer;
+}
+impl<A: Plus<B::Output>, B: Negate> Minus<B> for A {
+    type Output = <A as Plus<B::Output>>::Output;
+}
+
+pub trait Times<Rhs> {
+    type Output: Integer;
+}
+impl<B: Integer> Times<B> for Z0 {
+    type Output = Z0;
+}
+impl<A: NonNegative + Times<B>, B> Times<B> for Succ<A>
+where
+    A::Output: Plus<B>,
+{
+    type Output = <A::Output as Plus<B>>::Output;
+}
+impl<A: NonPositive + Times<B>, B> Times<B> for Pred<A>
+where
+    A::Output: Minus<B>,
+{
+    type Output = <A::Output as Minus<B>>::Output;
+}
+
+// Exact division, only implemented where the result is an integer: Halve<P4> = P2, Halve<P3> fails
+pub trait Halve {
+    type Output: Integer;
+}
+impl Halve for Z0 {
+    type Output = Z0;
+}
+impl<T: NonNegative + Halve> Halve for Succ<Succ<T>>
+where
+    T::Output: Inc,
+{
+    type Output = <T::Output as Inc>::Output;
+}
+impl<T: NonPositive + Halve> Halve for Pred<Pred<T>>
+where
+    T::Output: Dec,
+{
+    type Output = <T::Output as Dec>::Output;
+}
+
+pub trait Third {
+    type Output: Integer;
+}
+impl Third for Z0 {
+    type Output = Z0;
+}
+impl<T: NonNegative + Third> Third for Succ<Succ<Succ<T>>>
+where
+    T::Output: Inc,
+{
+    type Output = <T::Output as Inc>::Output;
+}
+impl<T: NonPositive + Third> Third for Pred<Pred<Pred<T>>>
+where
+    T::Output: Dec,
+{
+    type Output = <T::Output as Dec>::Output;
+}
+
+// ---------- const bridge ----------
+// Exponent<N> names the type-level integer for a const N, so macros can take exponents as
+// expressions: <Exponent<{ -2 }> as ToInteger>::Output is N2.
+pub struct Exponent<const N: i32>;
+
+pub trait ToInteger {
+    type Output: Integer;
+}
+
+macro_rules! to_integer {
+    ($($n:literal => $t:ty),* $(,)?) => {
+        $(impl ToInteger for Exponent<{ $n }> {
+            type Output = $t;
+        })*
+    };
+}
+to_integer!(
+    -9 => N9, -8 => N8, -7 => N7, -6 => N6, -5 => N5, -4 => N4, -3 => N3, -2 => N2, -1 => N1,
+    0 => Z0, 1 => P1, 2 => P2, 3 => P3, 4 => P4, 5 => P5, 6 => P6, 7 => P7, 8 => P8, 9 => P9,
+);
+
+// ================= dimension vectors =================
+// One exponent per SI base dimension (L, M, T, I, Θ, N, J), then a lane for plane angle (only
+// ever non-zero with the angle-dimension feature), then EXTRA_DIMENSIONS lanes X0..X2 an
+// application can give its own meaning (currency, requests, pixels). The lanes after the SI ones
+// default to zero, so Dim<L, M, T, I, Th, N, J> still names an SI dimension.
+
+pub const SI_DIMENSIONS: usize = 7;
+pub const ANGLE_DIMENSION: usize = SI_DIMENSIONS;
+pub const EXTRA_DIMENSIONS: usize = 3;
+// Lane of X0; X1 and X2 follow
+pub const FIRST_EXTRA_DIMENSION: usize = ANGLE_DIMENSION + 1;
+pub const DIMENSIONS: usize = FIRST_EXTRA_DIMENSION + EXTRA_DIMENSIONS;
+
+#[allow(clippy::type_complexity)]
+pub struct Dim<L, M, T, I, Th, N, J, A = Z0, X0 = Z0, X1 = Z0, X2 = Z0>(
+    PhantomData<(L, M, T, I, Th, N, J, A, X0, X1, X2)>,
+);
+
+pub trait Dimension {
+    const EXPONENTS: [i32; DIMENSIONS];
+}
+
+impl<L, M, T, I, Th, N, J, A, X0, X1, X2> Dimension for Dim<L, M, T, I, Th, N, J, A, X0, X1, X2>
+where
+    L: Integer,
+    M: Integer,
+    T: Integer,
+    I: Integer,
+    Th: Integer,
+    N: Integer,
+    J: Integer,
+    A: Integer,
+    X0: Integer,
+    X1: Integer,
+    X2: Integer,
+{
+    const EXPONENTS: [i32; DIMENSIONS] = [
+        L::VALUE,
+        M::VALUE,
+        T::VALUE,
+        I::VALUE,
+        Th::VALUE,
+        N::VALUE,
+        J::VALUE,
+        A::VALUE,
+        X0::VALUE,
+        X1::VALUE,
+        X2::VALUE,
+    ];
+}
+
+pub type Dimensionless = Dim<Z0, Z0, Z0, Z0, Z0, Z0, Z0>;
+
+// The seven base dimensions and the application-defined ones, e.g.
+//   quantity!(Money, dim: X0, Euro, "€") with gemini, or Quantity<dim::base::X0> with typed
+pub mod base {
+    use super::{Dim, P1, Z0};
+
+    pub type L = Dim<P1, Z0, Z0, Z0, Z0, Z0, Z0>;
+    pub type M = Dim<Z0, P1, Z0, Z0, Z0, Z0, Z0>;
+    pub type T = Dim<Z0, Z0, P1, Z0, Z0, Z0, Z0>;
+    pub type I = Dim<Z0, Z0, Z0, P1, Z0, Z0, Z0>;
+    pub type Th = Dim<Z0, Z0, Z0, Z0, P1, Z0, Z0>;
+    pub type N = Dim<Z0, Z0, Z0, Z0, Z0, P1, Z0>;
+    pub type J = Dim<Z0, Z0, Z0, Z0, Z0, Z0, P1>;
+    pub type X0 = Dim<Z0, Z0, Z0, Z0, Z0, Z0, Z0, Z0, P1, Z0, Z0>;
+    pub type X1 = Dim<Z0, Z0, Z0, Z0, Z0, Z0, Z0, Z0, Z0, P1, Z0>;
+    pub type X2 = Dim<Z0, Z0, Z0, Z0, Z0, Z0, Z0, Z0, Z0, Z0, P1>;
+
+    // Plane angle: dimensionless as in the SI, unless the `angle-dimension` feature tracks it in
+    // its own lane so that rad/s and Hz, or rad and 1, no longer mix
+    #[cfg(feature = "angle-dimension")]
+    pub type A = Dim<Z0, Z0, Z0, Z0, Z0, Z0, Z0, P1, Z0, Z0, Z0>;
+    #[cfg(not(feature = "angle-dimension"))]
+    pub type A = super::Dimensionless;
+}
+
+// ---------- arithmetic ----------
+pub trait Product<Rhs> {
+    type Output: Dimension;
+}
+impl<
+        L1,
+        M1,
+        T1,
+        I1,
+        Th1,
+        N1,
+        J1,
+        A1,
+        X01,
+        X11,
+        X21,
+        L2,
+        M2,
+        T2,
+        I2,
+        Th2,
+        N2,
+        J2,
+        A2,
+        X02,
+        X12,
+        X22,
+    > Product<Dim<L2, M2, T2, I2, Th2, N2, J2, A2, X02, X12, X22>>
+    for Dim<L1, M1, T1, I1, Th1, N1, J1, A1, X01, X11, X21>
+where
+    L1: Plus<L2>,
+    M1: Plus<M2>,
+    T1: Plus<T2>,
+    I1: Plus<I2>,
+    Th1: Plus<Th2>,
+    N1: Plus<N2>,
+    J1: Plus<J2>,
+    A1: Plus<A2>,
+    X01: Plus<X02>,
+    X11: Plus<X12>,
+    X21: Plus<X22>,
+{
+    type Output = Dim<
+        L1::Output,
+        M1::Output,
+        T1::Output,
+        I1::Output,
+        Th1::Output,
+        N1::Output,
+        J1::Output,
+        A1::Output,
+        X01::Output,
+        X11::Output,
+        X21::Output,
+    >;
+}
+
+pub trait Quotient<Rhs> {
+    type Output: Dimension;
+}
+impl<
+        L1,
+        M1,
+        T1,
+        I1,
+        Th1,
+        N1,
+        J1,
+        A1,
+        X01,
+        X11,
+        X21,
+        L2,
+        M2,
+        T2,
+        I2,
+        Th2,
+        N2,
+        J2,
+        A2,
+        X02,
+        X12,
+        X22,
+    > Quotient<Dim<L2, M2, T2, I2, Th2, N2, J2, A2, X02, X12, X22>>
+    for Dim<L1, M1, T1, I1, Th1, N1, J1, A1, X01, X11, X21>
+where
+    L1: Minus<L2>,
+    M1: Minus<M2>,
+    T1: Minus<T2>,
+    I1: Minus<I2>,
+    Th1: Minus<Th2>,
+    N1: Minus<N2>,
+    J1: Minus<J2>,
+    A1: Minus<A2>,
+    X01: Minus<X02>,
+    X11: Minus<X12>,
+    X21: Minus<X22>,
+{
+    type Output = Dim<
+        L1::Output,
+        M1::Output,
+        T1::Output,
+        I1::Output,
+        Th1::Output,
+        N1::Output,
+        J1::Output,
+        A1::Output,
+        X01::Output,
+        X11::Output,
+        X21::Output,
+    >;
+}
+
+pub trait Power<E> {
+    type Output: Dimension;
+}
+impl<L, M, T, I, Th, N, J, A, X0, X1, X2, E> Power<E> for Dim<L, M, T, I, Th, N, J, A, X0, X1, X2>
+where
+    L: Times<E>,
+    M: Times<E>,
+    T: Times<E>,
+    I: Times<E>,
+    Th: Times<E>,
+    N: Times<E>,
+    J: Times<E>,
+    A: Times<E>,
+    X0: Times<E>,
+    X1: Times<E>,
+    X2: Times<E>,
+{
+    type Output = Dim<
+        L::Output,
+        M::Output,
+        T::Output,
+        I::Output,
+        Th::Output,
+        N::Output,
+        J::Output,
+        A::Output,
+        X0::Output,
+        X1::Output,
+        X2::Output,
+    >;
+}
+
+// Roots exist for dimensions whose exponents all divide evenly (√(m²) = m, √Hz does not compile).
+// Type-level exponents are integers, so spectral densities such as V/√Hz have no gemini or typed
+// unit: rational exponents are a runtime-only feature, see temp::quantity::Exponent.
+pub trait SquareRoot {
+    type Output: Dimension;
+}
+impl<L, M, T, I, Th, N, J, A, X0, X1, X2> SquareRoot for Dim<L, M, T, I, Th, N, J, A, X0, X1, X2>
+where
+    L: Halve,
+    M: Halve,
+    T: Halve,
+    I: Halve,
+    Th: Halve,
+    N: Halve,
+    J: Halve,
+    A: Halve,
+    X0: Halve,
+    X1: Halve,
+    X2: Halve,
+{
+    type Output = Dim<
+        L::Output,
+        M::Output,
+        T::Output,
+        I::Output,
+        Th::Output,
+        N::Output,
+        J::Output,
+        A::Output,
+        X0::Output,
+        X1::Output,
+        X2::Output,
+    >;
+}
+
+pub trait CubeRoot {
+    type Output: Dimension;
+}
+impl<L, M, T, I, Th, N, J, A, X0, X1, X2> CubeRoot for Dim<L, M, T, I, Th, N, J, A, X0, X1, X2>
+where
+    L: Third,
+    M: Third,
+    T: Third,
+    I: Third,
+    Th: Third,
+    N: Third,
+    J: Third,
+    A: Third,
+    X0: Third,
+    X1: Third,
+    X2: Third,
+{
+    type Output = Dim<
+        L::Output,
+        M::Output,
+        T::Output,
+        I::Output,
+        Th::Output,
+        N::Output,
+        J::Output,
+        A::Output,
+        X0::Output,
+        X1::Output,
+        X2::Output,
+    >;
+}
+
+// ================= quantity kinds =================
+// Quantities of the same dimension can still be different kinds of thing: torque is not energy,
+// activity (Bq) is not frequency (Hz). A kind is a marker type; most quantities are Generic,
+// a quantity declared as a kind of its own is its own marker. Values only convert implicitly
+// within a kind, KindCast names the explicit reinterpretations that make sense.
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct Generic;
+
+// Implemented by the marker of every kind other than Generic. NAME is the kind as the runtime
+// API spells it in temp::quantity::Quantity::kind, e.g. "torque".
+pub trait Kind {
+    const NAME: &'static str;
+}
+
+// Runtime name of any kind marker, None for Generic
+pub trait KindName {
+    const NAME: Option<&'static str>;
+}
+impl KindName for Generic {
+    const NAME: Option<&'static str> = None;
+}
+impl<K: Kind> KindName for K {
+    const NAME: Option<&'static str> = Some(K::NAME);
+}
+
+// Generic values can be given a kind (N·m as torque) and kinds can be forgotten (Bq as 1/s),
+// but one kind never becomes another (Bq and rad/s are both 1/s when angles are dimensionless).
+// Only a Derived product of kinds, which is no particular kind yet, can be cast to any of them.
+pub trait KindCast<To> {}
+impl KindCast<Generic> for Generic {}
+impl<K: Kind> KindCast<K> for Generic {}
+impl<K: Kind> KindCast<Generic> for K {}
+impl<KA, KB, K: Kind> KindCast<K> for Derived<KA, KB> {}
+
+// Kind of a product or quotient. Generic operands give a generic result, but once an operand
+// has a kind of its own (bit, Bq) the result is Derived: bit/s is not Hz, and it converts to
+// nothing until cast to the kind it is read as, e.g. a data rate.
+///
+/// ```compile_fail
+/// use ferrunitas::gemini::units::{Bit, Hertz, Second};
+/// use ferrunitas::gemini::Value;
+///
+/// let rate = Value::<f64, Bit>::new(8.0) / Value::<f64, Second>::new(1.0);
+/// let _: Value<f64, Hertz> = rate.convert();
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct Derived<KA, KB>(PhantomData<(KA, KB)>);
+impl<KA, KB> Kind for Derived<KA, KB> {
+    const NAME: &'static str = DERIVED_KIND;
+}
+
+// Runtime name of Derived, the kind temp::quantity::Quantity gives such products
+pub const DERIVED_KIND: &str = "derived";
+
+pub trait KindProduct<Rhs> {
+    type Output: KindName;
+}
+impl KindProduct<Generic> for Generic {
+    type Output = Generic;
+}
+impl<K: Kind> KindProduct<K> for Generic {
+    type Output = Derived<Generic, K>;
+}
+impl<K: Kind> KindProduct<Generic> for K {
+    type Output = Derived<K, Generic>;
+}
+impl<KA: Kind, KB: Kind> KindProduct<KB> for KA {
+    type Output = Derived<KA, KB>;
+}
+
+// ================= formatting =================
+// Renders exponent vectors as dimension symbols (L·M·T⁻²) or SI base units (m·kg·s⁻²), in
+// Unicode, ASCII (m*kg/s^2) or LaTeX. DimensionDisplay implements Display, so the same output
+// serves dim::Dim, temp::quantity::Quantity and typed::quantity::Quantity.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Symbols {
+    Dimensions, // L, M, T, I, Θ, N, J
+    #[default]
+    BaseUnits, // m, kg, s, A, K, mol, cd
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Notation {
+    #[default]
+    Unicode, // m·kg·s⁻², s⁻¹ᐟ²
+    Ascii, // m*kg/s^2, s^(-1/2)
+    Latex, // \mathrm{m} \cdot \mathrm{kg} \cdot \mathrm{s}^{-2}
+}
+
+const DIMENSION_SYMBOLS: [&str; DIMENSIONS] =
+    ["L", "M", "T", "I", "Θ", "N", "J", "A", "X0", "X1", "X2"];
+const BASE_UNIT_SYMBOLS: [&str; DIMENSIONS] = [
+    "m", "kg", "s", "A", "K", "mol", "cd", "rad", "x0", "x1", "x2",
+];
+
+// Display symbols of the extra lanes, (dimension, base unit) for X0, X1 and X2
+pub type ExtraSymbols = [Option<(&'static str, &'static str)>; EXTRA_DIMENSIONS];
+
+// Names an application's extra lanes on a marker type, next to the quantities that use them:
+//   struct Shop;
+//   impl ExtraDimensions for Shop {
+//       const SYMBOLS: ExtraSymbols = [Some(("C", "€")), None, None];
+//   }
+// so that €/s shows as C·T⁻¹ rather than X0·T⁻¹, see DimensionDisplay::extra_dimensions
+pub trait ExtraDimensions {
+    const SYMBOLS: ExtraSymbols;
+}
+
+// Application-wide default of every DimensionDisplay, written once
+static EXTRA_DIMENSION_SYMBOLS: OnceLock<ExtraSymbols> = OnceLock::new();
+
+// Makes E's symbols the default of every DimensionDisplay, including those behind the Display
+// of quantities. Only the first call takes effect; false if it installed other symbols.
+pub fn name_extra_dimensions<E: ExtraDimensions>() -> bool {
+    *EXTRA_DIMENSION_SYMBOLS.get_or_init(|| E::SYMBOLS) == E::SYMBOLS
+}
+
+// Unicode superscript of a rational exponent: ², ⁻¹ or ⁻¹ᐟ²
+pub fn superscript(numerator: i32, denominator: i32) -> String {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    let digits = |n: i32| -> String {
+        n.unsigned_abs()
+            .to_string()
+            .chars()
+            .map(|c| DIGITS[c.to_digit(10).unwrap() as usize])
+            .collect()
+    };
+    let sign = if numerator < 0 { "⁻" } else { "" };
+    if denominator == 1 {
+        format!("{}{}", sign, digits(numerator))
+    } else {
+        format!("{}{}ᐟ{}", sign, digits(numerator), digits(denominator))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DimensionDisplay {
+    exponents: [(i32, i32); DIMENSIONS], // numerator, denominator (positive)
+    pub symbols: Symbols,
+    pub notation: Notation,
+    pub extra: ExtraSymbols,
+}
+
+impl DimensionDisplay {
+    pub fn new(exponents: [(i32, i32); DIMENSIONS]) -> Self {
+        DimensionDisplay {
+            exponents,
+            symbols: Symbols::default(),
+            notation: Notation::default(),
+            extra: EXTRA_DIMENSION_SYMBOLS
+                .get()
+                .copied()
+                .unwrap_or([None; EXTRA_DIMENSIONS]),
+        }
+    }
+
+    pub fn from_integers(exponents: [i32; DIMENSIONS]) -> Self {
+        DimensionDisplay::new(exponents.map(|e| (e, 1)))
+    }
+
+    pub fn of<D: Dimension>() -> Self {
+        DimensionDisplay::from_integers(D::EXPONENTS)
+    }
+
+    pub fn symbols(self, symbols: Symbols) -> Self {
+        DimensionDisplay { symbols, ..self }
+    }
+
+    pub fn notation(self, notation: Notation) -> Self {
+        DimensionDisplay { notation, ..self }
+    }
+
+    pub fn extra_dimensions<E: ExtraDimensions>(self) -> Self {
+        DimensionDisplay {
+            extra: E::SYMBOLS,
+            ..self
+        }
+    }
+
+    // Symbol and exponent of every non-zero lane, in lane order
+    fn factors(&self) -> impl Iterator<Item = (&'static str, i32, i32)> + '_ {
+        let mut symbols = match self.symbols {
+            Symbols::Dimensions => DIMENSION_SYMBOLS,
+            Symbols::BaseUnits => BASE_UNIT_SYMBOLS,
+        };
+        for (i, name) in self.extra.iter().enumerate() {
+            if let Some((dimension, base_unit)) = name {
+                symbols[FIRST_EXTRA_DIMENSION + i] = match self.symbols {
+                    Symbols::Dimensions => dimension,
+                    Symbols::BaseUnits => base_unit,
+                };
+            }
+        }
+        symbols
+            .into_iter()
+            .zip(self.exponents)
+            .filter(|(_, (n, _))| *n != 0)
+            .map(|(s, (n, d))| (s, n, d))
+    }
+
+    fn ascii_factor(symbol: &str, numerator: i32, denominator: i32) -> String {
+        match (numerator, denominator) {
+            (1, 1) => symbol.to_string(),
+            (n, 1) => format!("{}^{}", symbol, n),
+            (n, d) => format!("{}^({}/{})", symbol, n, d),
+        }
+    }
+
+    // Dimensions upright sans-serif as in the SI brochure, units upright roman
+    fn latex_symbol(&self, symbol: &str) -> String {
+        let symbol = if symbol == "Θ" { "\\Theta" } else { symbol };
+        match self.symbols {
+            Symbols::Dimensions => format!("\\mathsf{{{}}}", symbol),
+            Symbols::BaseUnits => format!("\\mathrm{{{}}}", symbol),
+        }
+    }
+}
+
+impl fmt::Display for DimensionDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.factors().next().is_none() {
+            return write!(f, "1");
+        }
+        match self.notation {
+            Notation::Unicode => {
+                let factors: Vec<String> = self
+                    .factors()
+                    .map(|(s, n, d)| match (n, d) {
+                        (1, 1) => s.to_string(),
+                        _ => format!("{}{}", s, superscript(n, d)),
+                    })
+                    .collect();
+                write!(f, "{}", factors.join("·"))
+            }
+            // Positive exponents over negative ones: m*kg/s^2, mol/(m^3*s), 1/s
+            Notation::Ascii => {
+                let numerator: Vec<String> = self
+                    .factors()
+                    .filter(|(_, n, _)| *n > 0)
+                    .map(|(s, n, d)| Self::ascii_factor(s, n, d))
+                    .collect();
+                let denominator: Vec<String> = self
+                    .factors()
+                    .filter(|(_, n, _)| *n < 0)
+                    .map(|(s, n, d)| Self::ascii_factor(s, -n, d))
+                    .collect();
+                if numerator.is_empty() {
+                    write!(f, "1")?;
+                } else {
+                    write!(f, "{}", numerator.join("*"))?;
+                }
+                match denominator.len() {
+                    0 => Ok(()),
+                    1 => write!(f, "/{}", denominator[0]),
+                    _ => write!(f, "/({})", denominator.join("*")),
+                }
+            }
+            Notation::Latex => {
+                let factors: Vec<String> = self
+                    .factors()
+                    .map(|(s, n, d)| match (n, d) {
+                        (1, 1) => self.latex_symbol(s),
+                        (n, 1) => format!("{}^{{{}}}", self.latex_symbol(s), n),
+                        (n, d) => format!("{}^{{{}/{}}}", self.latex_symbol(s), n, d),
+                    })
+                    .collect();
+                write!(f, "{}", factors.join(" \\cdot "))
+            }
+        }
+    }
+}
+
+// ================= macros =================
+// Maps an integer literal to its type-level representation
+#[macro_export]
+macro_rules! exponent {
+    (0) => {
+        $crate::dim::Z0
+    };
+    (1) => {
+        $crate::dim::P1
+    };
+    (2) => {
+        $crate::dim::P2
+    };
+    (3) => {
+        $crate::dim::P3
+    };
+    (4) => {
+        $crate::dim::P4
+    };
+    (5) => {
+        $crate::dim::P5
+    };
+    (6) => {
+        $crate::dim::P6
+    };
+    (7) => {
+        $crate::dim::P7
+    };
+    (8) => {
+        $crate::dim::P8
+    };
+    (9) => {
+        $crate::dim::P9
+    };
+    (-1) => {
+        $crate::dim::N1
+    };
+    (-2) => {
+        $crate::dim::N2
+    };
+    (-3) => {
+        $crate::dim::N3
+    };
+    (-4) => {
+        $crate::dim::N4
+    };
+    (-5) => {
+        $crate::dim::N5
+    };
+    (-6) => {
+        $crate::dim::N6
+    };
+    (-7) => {
+        $crate::dim::N7
+    };
+    (-8) => {
+        $crate::dim::N8
+    };
+    (-9) => {
+        $crate::dim::N9
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn angle_has_a_lane_of_its_own() {
+        let mut exponents = [0; DIMENSIONS];
+        if cfg!(feature = "angle-dimension") {
+            exponents[ANGLE_DIMENSION] = 1;
+        }
+        assert_eq!(<base::A as Dimension>::EXPONENTS, exponents);
+        for extra in [
+            <base::X0 as Dimension>::EXPONENTS,
+            <base::X1 as Dimension>::EXPONENTS,
+            <base::X2 as Dimension>::EXPONENTS,
+        ] {
+            assert_eq!(extra[ANGLE_DIMENSION], 0);
+        }
+        assert_eq!(<base::X0 as Dimension>::EXPONENTS[FIRST_EXTRA_DIMENSION], 1);
+        assert_eq!(<base::X2 as Dimension>::EXPONENTS[DIMENSIONS - 1], 1);
+    }
+
+    #[test]
+    fn dimensions_display_in_every_notation() {
+        type Force = Dim<P1, P1, N2, Z0, Z0, Z0, Z0>;
+        let force = DimensionDisplay::of::<Force>();
+        assert_eq!(force.to_string(), "m·kg·s⁻²");
+        assert_eq!(force.symbols(Symbols::Dimensions).to_string(), "L·M·T⁻²");
+        assert_eq!(force.notation(Notation::Ascii).to_string(), "m*kg/s^2");
+        assert_eq!(
+            force.notation(Notation::Latex).to_string(),
+            "\\mathrm{m} \\cdot \\mathrm{kg} \\cdot \\mathrm{s}^{-2}"
+        );
+
+        let mut noise = [(0, 1); DIMENSIONS];
+        noise[0] = (2, 1);
+        noise[1] = (1, 1);
+        noise[2] = (-5, 2);
+        noise[3] = (-1, 1);
+        let noise = DimensionDisplay::new(noise);
+        assert_eq!(noise.to_string(), "m²·kg·s⁻⁵ᐟ²·A⁻¹");
+        assert_eq!(
+            noise.notation(Notation::Ascii).to_string(),
+            "m^2*kg/(s^(5/2)*A)"
+        );
+        assert_eq!(DimensionDisplay::of::<Dimensionless>().to_string(), "1");
+        assert_eq!(superscript(-12, 1), "⁻¹²");
+        assert_eq!(superscript(1, 3), "¹ᐟ³");
+    }
+
+    #[test]
+    fn extra_dimensions_can_be_named() {
+        let mut exponents = [0; DIMENSIONS];
+        exponents[2] = -1;
+        exponents[FIRST_EXTRA_DIMENSION + 2] = 1;
+        let display = DimensionDisplay::from_integers(exponents);
+        let dimensions = display.symbols(Symbols::Dimensions);
+        assert_eq!(dimensions.to_string(), "T⁻¹·X2");
+        assert_eq!(display.to_string(), "s⁻¹·x2");
+
+        struct Screen;
+        impl ExtraDimensions for Screen {
+            const SYMBOLS: ExtraSymbols = [None, None, Some(("P", "px"))];
+        }
+        let dimensions = dimensions.extra_dimensions::<Screen>();
+        assert_eq!(dimensions.to_string(), "T⁻¹·P");
+        let display = display.extra_dimensions::<Screen>();
+        assert_eq!(display.notation(Notation::Ascii).to_string(), "px/s");
+        // Only extra lanes are named
+        assert_eq!(
+            DimensionDisplay::of::<base::T>()
+                .extra_dimensions::<Screen>()
+                .to_string(),
+            "s"
+        );
+    }
+}
@@ -1,15 +1,45 @@
 use std::fmt::Display;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use crate::dim::{Dimension, DimensionDisplay, KindCast, KindName, KindProduct, Product, Quotient};
+
+mod affine;
+mod angle;
+pub mod constants;
+mod log;
+pub mod prefixes;
+pub mod units;
+pub use affine::{Delta, Point};
+pub use log::{Absolute, Bel, Decibel, Gain, Level, LevelSub, LogUnit, Neper};
 
 // Core traits (Quantity and Unit)
 pub trait Quantity {
+    // Exponents of the SI base dimensions, e.g. Dim<P1, Z0, N2, ..> for acceleration.
+    type Dimension: Dimension;
+    // dim::Generic, or the quantity itself for kinds of their own (torque, activity)
+    type Kind: KindName;
     type BaseUnit: Unit<Quantity = Self>;
 }
 
 // A trait for units to define their conversion to the quantity's canonical base.
+// Affine units (°C, °F) additionally define where their zero lies, in quantity base units:
+// base = value * FACTOR_TO_QUANTITY_BASE + OFFSET_TO_QUANTITY_BASE
+// and say so in Zero, so that the type system can keep their values from being added.
 pub trait ConvertibleToQuantityBaseUnit {
     const FACTOR_TO_QUANTITY_BASE: f64;
+    const OFFSET_TO_QUANTITY_BASE: f64 = 0.0;
+    // BaseZero, or OffsetZero for units with an offset
+    type Zero;
 }
 
+// The unit's zero is that of the quantity base (m, K, km/h, Δ°C)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BaseZero;
+
+// The unit's zero is offset from that of the quantity base (°C, °F)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct OffsetZero;
+
 pub trait Unit: Copy + Clone + Default + Display {
     type Quantity: Quantity;
     type Base: Unit<Quantity = Self::Quantity>;
@@ -20,6 +50,9 @@ pub trait Prefix: Copy + Clone + Default + Display {
     const FACTOR: f64;
 }
 
+// Dimensionless quantity (ratios, gains) and its unit
+crate::quantity!(Ratio, [], One, "1");
+
 // Prefixed Unit
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct PrefixedUnit<P, U>(std::marker::PhantomData<(P, U)>);
@@ -42,7 +75,125 @@ impl<P: Prefix, U: Unit> Display for PrefixedUnit<P, U> {
     }
 }
 
-// Value
+// Product and quotient quantities, e.g. QuantityDiv<Length, Time> for velocity. They are generic
+// unless an operand has a kind of its own, see dim::KindProduct.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct QuantityMul<QA, QB>(std::marker::PhantomData<(QA, QB)>);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct QuantityDiv<QA, QB>(std::marker::PhantomData<(QA, QB)>);
+
+impl<QA: Quantity, QB: Quantity> Quantity for QuantityMul<QA, QB>
+where
+    QA::Dimension: Product<QB::Dimension>,
+    QA::Kind: KindProduct<QB::Kind>,
+{
+    type Dimension = <QA::Dimension as Product<QB::Dimension>>::Output;
+    type Kind = <QA::Kind as KindProduct<QB::Kind>>::Output;
+    type BaseUnit = UnitMul<QA::BaseUnit, QB::BaseUnit>;
+}
+
+impl<QA: Quantity, QB: Quantity> Quantity for QuantityDiv<QA, QB>
+where
+    QA::Dimension: Quotient<QB::Dimension>,
+    QA::Kind: KindProduct<QB::Kind>,
+{
+    type Dimension = <QA::Dimension as Quotient<QB::Dimension>>::Output;
+    type Kind = <QA::Kind as KindProduct<QB::Kind>>::Output;
+    type BaseUnit = UnitDiv<QA::BaseUnit, QB::BaseUnit>;
+}
+
+// Product and quotient units, e.g. UnitDiv<Kilometer, Hour>
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct UnitMul<UA, UB>(std::marker::PhantomData<(UA, UB)>);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct UnitDiv<UA, UB>(std::marker::PhantomData<(UA, UB)>);
+
+impl<UA: Unit, UB: Unit> Unit for UnitMul<UA, UB>
+where
+    QuantityMul<UA::Quantity, UB::Quantity>: Quantity,
+{
+    type Quantity = QuantityMul<UA::Quantity, UB::Quantity>;
+    type Base = Self;
+    const FACTOR_TO_UNIT_BASE: f64 = 1.0;
+}
+
+impl<UA: Unit, UB: Unit> Unit for UnitDiv<UA, UB>
+where
+    QuantityDiv<UA::Quantity, UB::Quantity>: Quantity,
+{
+    type Quantity = QuantityDiv<UA::Quantity, UB::Quantity>;
+    type Base = Self;
+    const FACTOR_TO_UNIT_BASE: f64 = 1.0;
+}
+
+// The quantity base of a product (quotient) is the product (quotient) of the operands' quantity
+// bases. Offsets play no part in products, °C·s is scaled like K·s.
+impl<UA: Unit, UB: Unit> ConvertibleToQuantityBaseUnit for UnitMul<UA, UB>
+where
+    UA::Base: ConvertibleToQuantityBaseUnit,
+    UB::Base: ConvertibleToQuantityBaseUnit,
+{
+    type Zero = BaseZero;
+    const FACTOR_TO_QUANTITY_BASE: f64 = UA::FACTOR_TO_UNIT_BASE
+        * <UA::Base as ConvertibleToQuantityBaseUnit>::FACTOR_TO_QUANTITY_BASE
+        * UB::FACTOR_TO_UNIT_BASE
+        * <UB::Base as ConvertibleToQuantityBaseUnit>::FACTOR_TO_QUANTITY_BASE;
+}
+
+impl<UA: Unit, UB: Unit> ConvertibleToQuantityBaseUnit for UnitDiv<UA, UB>
+where
+    UA::Base: ConvertibleToQuantityBaseUnit,
+    UB::Base: ConvertibleToQuantityBaseUnit,
+{
+    type Zero = BaseZero;
+    const FACTOR_TO_QUANTITY_BASE: f64 = UA::FACTOR_TO_UNIT_BASE
+        * <UA::Base as ConvertibleToQuantityBaseUnit>::FACTOR_TO_QUANTITY_BASE
+        / (UB::FACTOR_TO_UNIT_BASE
+            * <UB::Base as ConvertibleToQuantityBaseUnit>::FACTOR_TO_QUANTITY_BASE);
+}
+
+impl<UA, UB> Default for UnitMul<UA, UB> {
+    fn default() -> Self {
+        UnitMul(std::marker::PhantomData)
+    }
+}
+
+impl<UA, UB> Default for UnitDiv<UA, UB> {
+    fn default() -> Self {
+        UnitDiv(std::marker::PhantomData)
+    }
+}
+
+impl<UA: Unit, UB: Unit> Display for UnitMul<UA, UB> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}·{}", UA::default(), UB::default())
+    }
+}
+
+impl<UA: Unit, UB: Unit> Display for UnitDiv<UA, UB> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // A composite denominator is parenthesized, m³/(kg·s·s) rather than m³/kg·s·s.
+        let denominator = UB::default().to_string();
+        if denominator.contains(['·', '/']) {
+            write!(f, "{}/({})", UA::default(), denominator)
+        } else {
+            write!(f, "{}/{}", UA::default(), denominator)
+        }
+    }
+}
+
+// Value: a magnitude in a unit. In a unit with an offset (°C, °F) it is an absolute reading, so
+// convert applies the offset (20 °C is 293.15 K), and such values neither add nor subtract;
+// readings that move by an interval are Points, intervals are Values in Delta units (Δ°C).
+///
+/// ```compile_fail
+/// use ferrunitas::gemini::units::{Celsius, Kelvin};
+/// use ferrunitas::gemini::Value;
+///
+/// let _ = Value::<f64, Celsius>::new(20.0) + Value::<f64, Kelvin>::new(5.0);
+/// ```
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
 pub struct Value<V, U> {
     pub value: V,
@@ -59,6 +210,13 @@ impl<V, U> Value<V, U> {
     }
 }
 
+impl<V, U: Unit> Value<V, U> {
+    // Formatter for the value's dimension, e.g. m·kg·s⁻² for a force in any unit
+    pub fn dimension(&self) -> DimensionDisplay {
+        DimensionDisplay::of::<<U::Quantity as Quantity>::Dimension>()
+    }
+}
+
 impl<V, U> Value<V, U>
 where
     V: Copy + Into<f64> + From<f64>,
@@ -66,8 +224,11 @@ where
 {
     pub fn convert<TargetU: Unit>(self) -> Value<V, TargetU>
     where
-        // Ensures both units belong to the same physical quantity.
-        U::Quantity: PartialEq<TargetU::Quantity>,
+        // Ensures both units measure the same kind of quantity of the same physical dimension.
+        TargetU::Quantity: Quantity<
+            Dimension = <U::Quantity as Quantity>::Dimension,
+            Kind = <U::Quantity as Quantity>::Kind,
+        >,
         // Requires the raw base units to be convertible to the quantity's base.
         U::Base: ConvertibleToQuantityBaseUnit,
         TargetU::Base: ConvertibleToQuantityBaseUnit,
@@ -75,12 +236,14 @@ where
         // 1. Convert source value to its raw base (e.g., Millistone -> Stone).
         let value_in_raw_base = self.value.into() * U::FACTOR_TO_UNIT_BASE;
 
-        // 2. Convert raw base to quantity's canonical base (e.g., Stone -> Kilogram).
-        let value_in_quantity_base =
-            value_in_raw_base * <U::Base as ConvertibleToQuantityBaseUnit>::FACTOR_TO_QUANTITY_BASE;
+        // 2. Convert raw base to quantity's canonical base (e.g., Stone -> Kilogram, Celsius -> Kelvin).
+        let value_in_quantity_base = value_in_raw_base
+            * <U::Base as ConvertibleToQuantityBaseUnit>::FACTOR_TO_QUANTITY_BASE
+            + <U::Base as ConvertibleToQuantityBaseUnit>::OFFSET_TO_QUANTITY_BASE;
 
         // 3. Convert from the quantity's canonical base to the target's raw base (e.g., Kilogram -> Pound).
-        let value_to_target_base = value_in_quantity_base
+        let value_to_target_base = (value_in_quantity_base
+            - <TargetU::Base as ConvertibleToQuantityBaseUnit>::OFFSET_TO_QUANTITY_BASE)
             / <TargetU::Base as ConvertibleToQuantityBaseUnit>::FACTOR_TO_QUANTITY_BASE;
 
         // 4. Convert the target's raw base to the final target unit (e.g., Pound -> Megapound).
@@ -88,6 +251,143 @@ where
 
         Value::new(V::from(final_value))
     }
+
+    // Explicit change of kind between quantities of the same dimension, e.g. a generic N·m
+    // product read as a torque, or becquerels as plain 1/s. See dim::KindCast.
+    pub fn as_kind<TargetU: Unit>(self) -> Value<V, TargetU>
+    where
+        TargetU::Quantity: Quantity<Dimension = <U::Quantity as Quantity>::Dimension>,
+        <U::Quantity as Quantity>::Kind: KindCast<<TargetU::Quantity as Quantity>::Kind>,
+        U::Base: ConvertibleToQuantityBaseUnit,
+        TargetU::Base: ConvertibleToQuantityBaseUnit,
+    {
+        let value_in_quantity_base = self.value.into()
+            * U::FACTOR_TO_UNIT_BASE
+            * <U::Base as ConvertibleToQuantityBaseUnit>::FACTOR_TO_QUANTITY_BASE
+            + <U::Base as ConvertibleToQuantityBaseUnit>::OFFSET_TO_QUANTITY_BASE;
+        let final_value = (value_in_quantity_base
+            - <TargetU::Base as ConvertibleToQuantityBaseUnit>::OFFSET_TO_QUANTITY_BASE)
+            / <TargetU::Base as ConvertibleToQuantityBaseUnit>::FACTOR_TO_QUANTITY_BASE
+            / TargetU::FACTOR_TO_UNIT_BASE;
+        Value::new(V::from(final_value))
+    }
+}
+
+// Arithmetic between values yields a value in the product (quotient) unit.
+impl<V, UA, UB> Mul<Value<V, UB>> for Value<V, UA>
+where
+    V: Mul<Output = V>,
+    UnitMul<UA, UB>: Unit,
+{
+    type Output = Value<V, UnitMul<UA, UB>>;
+    fn mul(self, rhs: Value<V, UB>) -> Self::Output {
+        Value::new(self.value * rhs.value)
+    }
+}
+
+impl<V, UA, UB> Div<Value<V, UB>> for Value<V, UA>
+where
+    V: Div<Output = V>,
+    UnitDiv<UA, UB>: Unit,
+{
+    type Output = Value<V, UnitDiv<UA, UB>>;
+    fn div(self, rhs: Value<V, UB>) -> Self::Output {
+        Value::new(self.value / rhs.value)
+    }
+}
+
+// Scaling by a plain number keeps the unit.
+impl<V: Mul<Output = V>, U> Mul<V> for Value<V, U> {
+    type Output = Value<V, U>;
+    fn mul(self, rhs: V) -> Self::Output {
+        Value::new(self.value * rhs)
+    }
+}
+
+impl<V: Div<Output = V>, U> Div<V> for Value<V, U> {
+    type Output = Value<V, U>;
+    fn div(self, rhs: V) -> Self::Output {
+        Value::new(self.value / rhs)
+    }
+}
+
+// Sums and differences of the same quantity are expressed in the left-hand unit. Units with an
+// offset take no part: 20 °C is a reading, add intervals to it as a Point (see affine).
+impl<V, UA, UB> Add<Value<V, UB>> for Value<V, UA>
+where
+    V: Copy + Into<f64> + From<f64> + Add<Output = V>,
+    UA: Unit,
+    UB: Unit,
+    UA::Quantity: Quantity<
+        Dimension = <UB::Quantity as Quantity>::Dimension,
+        Kind = <UB::Quantity as Quantity>::Kind,
+    >,
+    UA::Base: ConvertibleToQuantityBaseUnit<Zero = BaseZero>,
+    UB::Base: ConvertibleToQuantityBaseUnit<Zero = BaseZero>,
+{
+    type Output = Value<V, UA>;
+    fn add(self, rhs: Value<V, UB>) -> Self::Output {
+        Value::new(self.value + rhs.convert::<UA>().value)
+    }
+}
+
+impl<V, UA, UB> Sub<Value<V, UB>> for Value<V, UA>
+where
+    V: Copy + Into<f64> + From<f64> + Sub<Output = V>,
+    UA: Unit,
+    UB: Unit,
+    UA::Quantity: Quantity<
+        Dimension = <UB::Quantity as Quantity>::Dimension,
+        Kind = <UB::Quantity as Quantity>::Kind,
+    >,
+    UA::Base: ConvertibleToQuantityBaseUnit<Zero = BaseZero>,
+    UB::Base: ConvertibleToQuantityBaseUnit<Zero = BaseZero>,
+{
+    type Output = Value<V, UA>;
+    fn sub(self, rhs: Value<V, UB>) -> Self::Output {
+        Value::new(self.value - rhs.convert::<UA>().value)
+    }
+}
+
+impl<V, UA, UB> AddAssign<Value<V, UB>> for Value<V, UA>
+where
+    V: Copy + Into<f64> + From<f64> + AddAssign,
+    UA: Unit,
+    UB: Unit,
+    UA::Quantity: Quantity<
+        Dimension = <UB::Quantity as Quantity>::Dimension,
+        Kind = <UB::Quantity as Quantity>::Kind,
+    >,
+    UA::Base: ConvertibleToQuantityBaseUnit<Zero = BaseZero>,
+    UB::Base: ConvertibleToQuantityBaseUnit<Zero = BaseZero>,
+{
+    fn add_assign(&mut self, rhs: Value<V, UB>) {
+        self.value += rhs.convert::<UA>().value;
+    }
+}
+
+impl<V, UA, UB> SubAssign<Value<V, UB>> for Value<V, UA>
+where
+    V: Copy + Into<f64> + From<f64> + SubAssign,
+    UA: Unit,
+    UB: Unit,
+    UA::Quantity: Quantity<
+        Dimension = <UB::Quantity as Quantity>::Dimension,
+        Kind = <UB::Quantity as Quantity>::Kind,
+    >,
+    UA::Base: ConvertibleToQuantityBaseUnit<Zero = BaseZero>,
+    UB::Base: ConvertibleToQuantityBaseUnit<Zero = BaseZero>,
+{
+    fn sub_assign(&mut self, rhs: Value<V, UB>) {
+        self.value -= rhs.convert::<UA>().value;
+    }
+}
+
+impl<V: Neg<Output = V>, U> Neg for Value<V, U> {
+    type Output = Value<V, U>;
+    fn neg(self) -> Self::Output {
+        Value::new(-self.value)
+    }
 }
 
 impl<V, U> Display for Value<V, U>
@@ -123,14 +423,44 @@ macro_rules! prefix {
             }
         }
     };
+
+    // Pattern for the prefix types standing for a runtime SIPrefix (e.g., Kilo for SIPrefix::Kilo)
+    ($name:ident, si: $variant:ident, $factor:literal, $symbol:literal) => {
+        $crate::prefix!($name, $factor, $symbol);
+
+        impl From<$name> for $crate::temp::prefixes::SIPrefix {
+            fn from(_: $name) -> Self {
+                $crate::temp::prefixes::SIPrefix::$variant
+            }
+        }
+
+        // Fails with the given prefix when it is not the one this type stands for.
+        impl TryFrom<$crate::temp::prefixes::SIPrefix> for $name {
+            type Error = $crate::temp::prefixes::SIPrefix;
+            fn try_from(prefix: $crate::temp::prefixes::SIPrefix) -> Result<Self, Self::Error> {
+                match prefix {
+                    $crate::temp::prefixes::SIPrefix::$variant => Ok($name),
+                    other => Err(other),
+                }
+            }
+        }
+    };
+}
+
+// Type-level dimension of a product of quantity powers, e.g. dimension!((Mass, 1), (Length, 1), (Time, -2))
+#[macro_export]
+macro_rules! dimension {
+    () => { $crate::dim::Dimensionless };
+    (($qty:ty, $($pow:tt)+) $(, ($rest_qty:ty, $($rest_pow:tt)+))* $(,)?) => {
+        <<<$qty as $crate::gemini::Quantity>::Dimension as $crate::dim::Power<$crate::exponent!($($pow)+)>>::Output
+            as $crate::dim::Product<$crate::dimension!($(($rest_qty, $($rest_pow)+)),*)>>::Output
+    };
 }
 
 #[macro_export]
 macro_rules! quantity {
     // Pattern for when the base unit of the quantity is prefixed (kg for mass)
-    ($qty_name:ident, ($base_unit:ident, $prefix_name:ident, $raw_unit_name:ident), $base_unit_symbol:literal) => {
-        use $crate::gemini::Prefix;
-
+    ($qty_name:ident, dim: $dim:ident, ($base_unit:ident, $prefix_name:ident, $raw_unit_name:ident), $base_unit_symbol:literal) => {
         // Declare the structs for the quantity and its base unit.
         // These need to exist before we can refer to them in the implementations.
         #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -144,22 +474,26 @@ macro_rules! quantity {
 
         // Implement the Quantity trait for the quantity.
         impl $crate::gemini::Quantity for $qty_name {
+            type Dimension = $crate::dim::base::$dim;
+            type Kind = $crate::dim::Generic;
             type BaseUnit = $base_unit;
         }
 
-        // Implement the Unit trait for the raw base unit.
+        // Implement the Unit trait for the raw base unit. It is its own base, like any other
+        // unprefixed unit, so that the prefixed base unit (and units chained to it) resolve
+        // through it.
         impl $crate::gemini::Unit for $raw_unit_name {
             type Quantity = $qty_name;
-            type Base = $base_unit;
-
-            // The conversion factor from this unit to the base_unit is the prefix factor
-            const FACTOR_TO_UNIT_BASE: f64 = $prefix_name::FACTOR;
+            type Base = $raw_unit_name;
+            const FACTOR_TO_UNIT_BASE: f64 = 1.0;
         }
 
         // Implement ConvertibleToQuantityBaseUnit  trait for the raw base unit.
         impl $crate::gemini::ConvertibleToQuantityBaseUnit for $raw_unit_name {
-            // Conversion factor to the quantity's base unit is the inverse of the factor to the raw base.
-            const FACTOR_TO_QUANTITY_BASE: f64 = 1.0 / $base_unit::FACTOR_TO_UNIT_BASE;
+            // Conversion factor to the quantity's base unit is the inverse of the prefix factor.
+            const FACTOR_TO_QUANTITY_BASE: f64 =
+                1.0 / <$prefix_name as $crate::gemini::Prefix>::FACTOR;
+            type Zero = $crate::gemini::BaseZero;
         }
 
         impl Default for $raw_unit_name {
@@ -176,7 +510,49 @@ macro_rules! quantity {
     };
 
     // Pattern for when the base unit of the quantity is not prefixed (e.g., s for time)
-    ($qty_name:ident, $base_unit:ident, $base_unit_symbol:literal) => {
+    ($qty_name:ident, dim: $dim:ident, $base_unit:ident, $base_unit_symbol:literal) => {
+        $crate::quantity!(@coherent $qty_name, $crate::dim::base::$dim, $crate::dim::Generic, $base_unit, $base_unit_symbol);
+    };
+
+    // Pattern for a base quantity that is also a kind of its own (e.g., the plane angle, which
+    // is dimensionless unless the angle-dimension feature is enabled)
+    (kind $qty_name:ident = $kind_name:literal, dim: $dim:ident, $base_unit:ident, $base_unit_symbol:literal) => {
+        $crate::quantity!(@coherent $qty_name, $crate::dim::base::$dim, $qty_name, $base_unit, $base_unit_symbol);
+        impl $crate::dim::Kind for $qty_name {
+            const NAME: &'static str = $kind_name;
+        }
+    };
+
+    // Pattern for derived quantities given as a product of powers of other quantities
+    // (e.g., Force = Mass * Length * Time^-2 with newton as base unit)
+    ($qty_name:ident, [ $( ($component:ty, $($pow:tt)+) ),* $(,)? ], $base_unit:ident, $base_unit_symbol:literal) => {
+        $crate::quantity!(
+            @coherent $qty_name,
+            $crate::dimension!($(($component, $($pow)+)),*),
+            $crate::dim::Generic,
+            $base_unit,
+            $base_unit_symbol
+        );
+    };
+
+    // Pattern for a kind of its own, which shares its dimension with other quantities but does not
+    // convert to them implicitly (e.g., kind Torque = "torque", Force * Length, apart from Energy).
+    // The name is the kind of the matching temp quantity, so parsing can tell the kinds apart.
+    (kind $qty_name:ident = $kind_name:literal, [ $( ($component:ty, $($pow:tt)+) ),* $(,)? ], $base_unit:ident, $base_unit_symbol:literal) => {
+        $crate::quantity!(
+            @coherent $qty_name,
+            $crate::dimension!($(($component, $($pow)+)),*),
+            $qty_name,
+            $base_unit,
+            $base_unit_symbol
+        );
+        impl $crate::dim::Kind for $qty_name {
+            const NAME: &'static str = $kind_name;
+        }
+    };
+
+    // Quantity with an unprefixed base unit of the given dimension
+    (@coherent $qty_name:ident, $dim:ty, $kind:ty, $base_unit:ident, $base_unit_symbol:literal) => {
         // Declare the structs for the quantity and its base unit.
         // These need to exist before we can refer to them in the implementations.
         #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -187,6 +563,8 @@ macro_rules! quantity {
 
         // Implement the Quantity trait for the quantity.
         impl $crate::gemini::Quantity for $qty_name {
+            type Dimension = $dim;
+            type Kind = $kind;
             type BaseUnit = $base_unit;
         }
 
@@ -200,6 +578,7 @@ macro_rules! quantity {
         // Implement ConvertibleToQuantityBaseUnit  trait for the raw base unit.
         impl $crate::gemini::ConvertibleToQuantityBaseUnit for $base_unit {
             const FACTOR_TO_QUANTITY_BASE: f64 = 1.0;
+            type Zero = $crate::gemini::BaseZero;
         }
 
         impl Default for $base_unit {
@@ -218,13 +597,65 @@ macro_rules! quantity {
 
 #[macro_export]
 macro_rules! unit {
+    // Pattern for units defined relative to another unit (e.g., Foot = 12 Inch). The quantity is
+    // taken from the other unit and the factor to the quantity base is composed at compile time.
+    // The factor is a literal, a fraction of literals (1.0 / 3.0 * Hour) or any expression in
+    // parentheses.
+    ($name:ident, $numerator:literal / $denominator:literal * $other:ty, $symbol:literal) => {
+        $crate::unit!($name, ($numerator / $denominator) * $other, $symbol);
+    };
+
+    ($name:ident, $factor:tt * $other:ty, $symbol:literal) => {
+        #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+        pub struct $name;
+
+        impl $crate::gemini::ConvertibleToQuantityBaseUnit for $name {
+            const FACTOR_TO_QUANTITY_BASE: f64 = $factor
+                * <$other as $crate::gemini::Unit>::FACTOR_TO_UNIT_BASE
+                * <<$other as $crate::gemini::Unit>::Base as $crate::gemini::ConvertibleToQuantityBaseUnit>::FACTOR_TO_QUANTITY_BASE;
+            const OFFSET_TO_QUANTITY_BASE: f64 =
+                <<$other as $crate::gemini::Unit>::Base as $crate::gemini::ConvertibleToQuantityBaseUnit>::OFFSET_TO_QUANTITY_BASE;
+            type Zero =
+                <<$other as $crate::gemini::Unit>::Base as $crate::gemini::ConvertibleToQuantityBaseUnit>::Zero;
+        }
+
+        impl $crate::gemini::Unit for $name {
+            type Quantity = <$other as $crate::gemini::Unit>::Quantity;
+            type Base = $name;
+            const FACTOR_TO_UNIT_BASE: f64 = 1.0;
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, $symbol)
+            }
+        }
+    };
+
     ($name:ident, $quantity:ty, $conversion:expr, $symbol:literal) => {
+        $crate::unit!(@scaled $name, $quantity, $conversion, 0.0, $crate::gemini::BaseZero, $symbol);
+    };
+
+    // Pattern for affine units whose zero is offset from the base unit's zero (e.g., °C)
+    ($name:ident, $quantity:ty, $conversion:expr, offset: $offset:expr, $symbol:literal) => {
+        $crate::unit!(@scaled $name, $quantity, $conversion, $offset, $crate::gemini::OffsetZero, $symbol);
+    };
+
+    (@scaled $name:ident, $quantity:ty, $conversion:expr, $offset:expr, $zero:ty, $symbol:literal) => {
         #[derive(Debug, Copy, Clone, PartialEq, Eq)]
         pub struct $name;
 
-        // Implement ConvertibleToBase to set the factor to the quantity's base unit.
+        // Implement ConvertibleToBase to set the factor and offset to the quantity's base unit.
         impl $crate::gemini::ConvertibleToQuantityBaseUnit for $name {
             const FACTOR_TO_QUANTITY_BASE: f64 = $conversion;
+            const OFFSET_TO_QUANTITY_BASE: f64 = $offset;
+            type Zero = $zero;
         }
 
         impl $crate::gemini::Unit for $name {
@@ -246,3 +677,171 @@ macro_rules! unit {
         }
     };
 }
+
+#[macro_export]
+macro_rules! log_unit {
+    // Pattern for decibel-style units of power quantities: 10 * log10(P / P0) (e.g., dBm)
+    ($name:ident, $linear:ty, $reference:expr, power, $symbol:literal) => {
+        $crate::log_unit!($name, $linear, reference: $reference, base: 10.0, multiplier: 10.0, root_power: false, $symbol);
+    };
+
+    // Pattern for decibel-style units of root-power quantities: 20 * log10(F / F0) (e.g., dBV)
+    ($name:ident, $linear:ty, $reference:expr, root_power, $symbol:literal) => {
+        $crate::log_unit!($name, $linear, reference: $reference, base: 10.0, multiplier: 20.0, root_power: true, $symbol);
+    };
+
+    ($name:ident, $linear:ty, reference: $reference:expr, base: $base:expr, multiplier: $multiplier:expr, root_power: $root_power:expr, $symbol:literal) => {
+        $crate::log_unit!(@define $name, $linear, $reference, $base, $multiplier, $root_power, $crate::gemini::Absolute, $symbol);
+    };
+
+    // Pattern for gains, dimensionless ratios that add to and subtract from levels (e.g., dB, Np)
+    (gain $name:ident, base: $base:expr, multiplier: $multiplier:expr, root_power: $root_power:expr, $symbol:literal) => {
+        $crate::log_unit!(@define $name, $crate::gemini::One, 1.0, $base, $multiplier, $root_power, $crate::gemini::Gain, $symbol);
+    };
+
+    (@define $name:ident, $linear:ty, $reference:expr, $base:expr, $multiplier:expr, $root_power:expr, $role:ty, $symbol:literal) => {
+        #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+        pub struct $name;
+
+        impl $crate::gemini::LogUnit for $name {
+            type Linear = $linear;
+            type Role = $role;
+            const REFERENCE: f64 = $reference;
+            const BASE: f64 = $base;
+            const MULTIPLIER: f64 = $multiplier;
+            const ROOT_POWER: bool = $root_power;
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, $symbol)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::prefixes::Kilo;
+    use super::units::{
+        Angle, Bit, BitPerSecond, Byte, Celsius, Hertz, Hour, Information, Joule, Kelvin, Kilogram,
+        Meter, Mile, Newton, Radian, Second,
+    };
+    use super::{Delta, PrefixedUnit, Quantity, Unit, UnitDiv, UnitMul, Value};
+    use crate::dim::{Derived, Generic};
+    use std::any::TypeId;
+
+    fn kind<U: Unit>() -> TypeId
+    where
+        <U::Quantity as Quantity>::Kind: 'static,
+    {
+        TypeId::of::<<U::Quantity as Quantity>::Kind>()
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-9 * b.abs().max(1.0)
+    }
+
+    #[test]
+    fn values_convert_between_units_of_a_quantity() {
+        type Kilometer = PrefixedUnit<Kilo, Meter>;
+        type KilometerPerHour = UnitDiv<Kilometer, Hour>;
+        type MeterPerSecond = UnitDiv<Meter, Second>;
+
+        let marathon = Value::<f64, Kilometer>::new(42.195);
+        assert!(close(marathon.convert::<Meter>().value, 42_195.0));
+        assert!(close(marathon.convert::<Mile>().value, 42_195.0 / 1609.344));
+        assert!(close(
+            Value::<f64, KilometerPerHour>::new(36.0)
+                .convert::<MeterPerSecond>()
+                .value,
+            10.0
+        ));
+        let pace = marathon / Value::<f64, Hour>::new(2.0);
+        assert!(close(
+            pace.convert::<MeterPerSecond>().value,
+            42_195.0 / 7200.0
+        ));
+        let work: Value<f64, Joule> =
+            (Value::<f64, Newton>::new(2.0) * Value::<f64, Kilometer>::new(3.0)).convert();
+        assert!(close(work.value, 6000.0));
+        assert_eq!(marathon.to_string(), "42.195 km");
+    }
+
+    #[test]
+    fn chained_units_take_fractions_and_parenthesized_factors() {
+        crate::unit!(ThirdOfAnHour, 1.0 / 3.0 * Hour, "h/3");
+        crate::unit!(Fortnight, (14.0 * 24.0) * Hour, "fn");
+
+        assert!(close(
+            Value::<f64, ThirdOfAnHour>::new(1.0)
+                .convert::<Second>()
+                .value,
+            1200.0
+        ));
+        assert!(close(
+            Value::<f64, Fortnight>::new(1.0).convert::<Second>().value,
+            1_209_600.0
+        ));
+        assert_eq!(kind::<ThirdOfAnHour>(), kind::<Hour>());
+    }
+
+    #[test]
+    fn information_is_a_kind_of_its_own() {
+        assert_eq!(kind::<Byte>(), TypeId::of::<Information>());
+        assert_ne!(kind::<Byte>(), kind::<Radian>());
+        assert_ne!(kind::<BitPerSecond>(), kind::<Hertz>());
+        assert_eq!(kind::<Radian>(), TypeId::of::<Angle>());
+        assert_eq!(
+            kind::<UnitDiv<Bit, Second>>(),
+            TypeId::of::<Derived<Information, Generic>>()
+        );
+        assert_eq!(kind::<UnitDiv<Meter, Second>>(), TypeId::of::<Generic>());
+        assert_eq!(
+            kind::<UnitDiv<Radian, Second>>(),
+            TypeId::of::<Derived<Angle, Generic>>()
+        );
+
+        let byte = Value::<f64, Byte>::new(1.0);
+        assert_eq!(byte.convert::<Bit>().value, 8.0);
+        let rate: Value<f64, BitPerSecond> = (byte / Value::<f64, Second>::new(2.0)).as_kind();
+        assert_eq!(rate.value, 4.0);
+    }
+
+    #[test]
+    fn sums_take_units_without_an_offset() {
+        let k = Value::<f64, Kelvin>::new(5.0) + Value::<f64, Delta<Celsius>>::new(5.0);
+        assert!((k.value - 10.0).abs() < 1e-12);
+        let k = Value::<f64, Kelvin>::new(5.0) - Value::<f64, Delta<Celsius>>::new(3.0);
+        assert!((k.value - 2.0).abs() < 1e-12);
+
+        let mut total = Value::<f64, Delta<Celsius>>::new(20.0);
+        total += Value::<f64, Kelvin>::new(1.0);
+        total -= Value::<f64, Kelvin>::new(3.0);
+        assert!((total.value - 18.0).abs() < 1e-12);
+        // A reading in °C still converts with the offset
+        let reading = Value::<f64, Celsius>::new(20.0);
+        assert!((reading.convert::<Kelvin>().value - 293.15).abs() < 1e-12);
+    }
+
+    #[test]
+    fn quotient_display_parenthesizes_composite_denominators() {
+        type MeterPerSecond = UnitDiv<Meter, Second>;
+        type Gravitational = UnitDiv<
+            UnitMul<Meter, UnitMul<Meter, Meter>>,
+            UnitMul<Kilogram, UnitMul<Second, Second>>,
+        >;
+        assert_eq!(MeterPerSecond::default().to_string(), "m/s");
+        assert_eq!(
+            UnitDiv::<MeterPerSecond, Second>::default().to_string(),
+            "m/s/s"
+        );
+        assert_eq!(Gravitational::default().to_string(), "m·m·m/(kg·s·s)");
+    }
+}
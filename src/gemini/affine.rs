@@ -0,0 +1,233 @@
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+use super::{BaseZero, ConvertibleToQuantityBaseUnit, Quantity, Unit, Value};
+
+// Affine space over Value: a Point is an absolute position on a scale (a temperature reading,
+// a timestamp, an altitude above a datum), a Value in a Delta unit is the distance between two.
+//   point - point    = interval
+//   point ± interval = point
+//   point + point    does not compile, nor does any sum of values in units with an offset
+
+// Interval unit of a (possibly affine) unit: same scale, zero offset, e.g. Δ°C
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Delta<U>(std::marker::PhantomData<U>);
+
+impl<U: Unit> Unit for Delta<U> {
+    type Quantity = U::Quantity;
+    type Base = Self;
+    const FACTOR_TO_UNIT_BASE: f64 = 1.0;
+}
+
+impl<U: Unit> ConvertibleToQuantityBaseUnit for Delta<U>
+where
+    U::Base: ConvertibleToQuantityBaseUnit,
+{
+    // Only the scale carries over, the offset of U cancels out in every difference.
+    const FACTOR_TO_QUANTITY_BASE: f64 = U::FACTOR_TO_UNIT_BASE
+        * <U::Base as ConvertibleToQuantityBaseUnit>::FACTOR_TO_QUANTITY_BASE;
+    type Zero = BaseZero;
+}
+
+impl<U> Default for Delta<U> {
+    fn default() -> Self {
+        Delta(std::marker::PhantomData)
+    }
+}
+
+impl<U: Unit> Display for Delta<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Δ{}", U::default())
+    }
+}
+
+// Point
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Point<V, U> {
+    pub value: V,
+    _unit: std::marker::PhantomData<U>,
+}
+
+impl<V, U> Point<V, U> {
+    // A constructor for creating a new Point.
+    pub const fn new(value: V) -> Self {
+        Point {
+            value,
+            _unit: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<V, U> Point<V, U>
+where
+    V: Copy + Into<f64> + From<f64>,
+    U: Unit,
+{
+    // Points convert like values, including the offset between the scales' zeros.
+    pub fn convert<TargetU: Unit>(self) -> Point<V, TargetU>
+    where
+        TargetU::Quantity: Quantity<
+            Dimension = <U::Quantity as Quantity>::Dimension,
+            Kind = <U::Quantity as Quantity>::Kind,
+        >,
+        U::Base: ConvertibleToQuantityBaseUnit,
+        TargetU::Base: ConvertibleToQuantityBaseUnit,
+    {
+        Point::new(Value::<V, U>::new(self.value).convert::<TargetU>().value)
+    }
+}
+
+// point - point = interval, expressed in the left-hand unit's scale
+impl<V, UA, UB> Sub<Point<V, UB>> for Point<V, UA>
+where
+    V: Copy + Into<f64> + From<f64> + Sub<Output = V>,
+    UA: Unit,
+    UB: Unit,
+    UA::Quantity: Quantity<
+        Dimension = <UB::Quantity as Quantity>::Dimension,
+        Kind = <UB::Quantity as Quantity>::Kind,
+    >,
+    UA::Base: ConvertibleToQuantityBaseUnit,
+    UB::Base: ConvertibleToQuantityBaseUnit,
+{
+    type Output = Value<V, Delta<UA>>;
+    fn sub(self, rhs: Point<V, UB>) -> Self::Output {
+        Value::new(self.value - rhs.convert::<UA>().value)
+    }
+}
+
+// point ± interval = point; the interval is a Value in a unit without an offset, Δ°F or K
+impl<V, UA, UB> Add<Value<V, UB>> for Point<V, UA>
+where
+    V: Copy + Into<f64> + From<f64> + Add<Output = V>,
+    UA: Unit,
+    UB: Unit,
+    UA::Quantity: Quantity<
+        Dimension = <UB::Quantity as Quantity>::Dimension,
+        Kind = <UB::Quantity as Quantity>::Kind,
+    >,
+    UA::Base: ConvertibleToQuantityBaseUnit,
+    UB::Base: ConvertibleToQuantityBaseUnit<Zero = BaseZero>,
+{
+    type Output = Point<V, UA>;
+    fn add(self, rhs: Value<V, UB>) -> Self::Output {
+        let interval = Value::<V, Delta<UB>>::new(rhs.value).convert::<Delta<UA>>();
+        Point::new(self.value + interval.value)
+    }
+}
+
+impl<V, UA, UB> Sub<Value<V, UB>> for Point<V, UA>
+where
+    V: Copy + Into<f64> + From<f64> + Sub<Output = V>,
+    UA: Unit,
+    UB: Unit,
+    UA::Quantity: Quantity<
+        Dimension = <UB::Quantity as Quantity>::Dimension,
+        Kind = <UB::Quantity as Quantity>::Kind,
+    >,
+    UA::Base: ConvertibleToQuantityBaseUnit,
+    UB::Base: ConvertibleToQuantityBaseUnit<Zero = BaseZero>,
+{
+    type Output = Point<V, UA>;
+    fn sub(self, rhs: Value<V, UB>) -> Self::Output {
+        let interval = Value::<V, Delta<UB>>::new(rhs.value).convert::<Delta<UA>>();
+        Point::new(self.value - interval.value)
+    }
+}
+
+impl<V, UA, UB> AddAssign<Value<V, UB>> for Point<V, UA>
+where
+    V: Copy + Into<f64> + From<f64> + AddAssign,
+    UA: Unit,
+    UB: Unit,
+    UA::Quantity: Quantity<
+        Dimension = <UB::Quantity as Quantity>::Dimension,
+        Kind = <UB::Quantity as Quantity>::Kind,
+    >,
+    UA::Base: ConvertibleToQuantityBaseUnit,
+    UB::Base: ConvertibleToQuantityBaseUnit<Zero = BaseZero>,
+{
+    fn add_assign(&mut self, rhs: Value<V, UB>) {
+        self.value += Value::<V, Delta<UB>>::new(rhs.value)
+            .convert::<Delta<UA>>()
+            .value;
+    }
+}
+
+impl<V, UA, UB> SubAssign<Value<V, UB>> for Point<V, UA>
+where
+    V: Copy + Into<f64> + From<f64> + SubAssign,
+    UA: Unit,
+    UB: Unit,
+    UA::Quantity: Quantity<
+        Dimension = <UB::Quantity as Quantity>::Dimension,
+        Kind = <UB::Quantity as Quantity>::Kind,
+    >,
+    UA::Base: ConvertibleToQuantityBaseUnit,
+    UB::Base: ConvertibleToQuantityBaseUnit<Zero = BaseZero>,
+{
+    fn sub_assign(&mut self, rhs: Value<V, UB>) {
+        self.value -= Value::<V, Delta<UB>>::new(rhs.value)
+            .convert::<Delta<UA>>()
+            .value;
+    }
+}
+
+impl<V, U> Display for Point<V, U>
+where
+    V: Display,
+    U: Display + Default,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.value, U::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gemini::units::{Celsius, Kelvin, Temperature};
+
+    crate::unit!(Fahrenheit, Temperature, 5.0 / 9.0, offset: 459.67 * 5.0 / 9.0, "°F");
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-9 * b.abs().max(1.0)
+    }
+
+    #[test]
+    fn points_convert_with_the_offset() {
+        let boiling = Point::<f64, Celsius>::new(100.0);
+        assert!(close(boiling.convert::<Kelvin>().value, 373.15));
+        assert!(close(boiling.convert::<Fahrenheit>().value, 212.0));
+        let cold = Point::<f64, Fahrenheit>::new(-40.0);
+        assert!(close(cold.convert::<Celsius>().value, -40.0));
+        assert_eq!(boiling.to_string(), "100 °C");
+    }
+
+    #[test]
+    fn differences_are_intervals() {
+        let morning = Point::<f64, Celsius>::new(12.0);
+        let noon = Point::<f64, Fahrenheit>::new(77.0);
+        let rise: Value<f64, Delta<Celsius>> = noon.convert::<Celsius>() - morning;
+        assert!(close(rise.value, 13.0));
+        assert!(close((noon - morning).value, 23.4));
+        assert!(close(rise.convert::<Delta<Fahrenheit>>().value, 23.4));
+        assert!(close(rise.convert::<Delta<Kelvin>>().value, 13.0));
+        assert_eq!(rise.to_string(), "13 Δ°C");
+    }
+
+    #[test]
+    fn intervals_move_points() {
+        let start = Point::<f64, Celsius>::new(20.0);
+        assert!(close((start + Value::<f64, Kelvin>::new(5.0)).value, 25.0));
+        assert!(close((start - Value::<f64, Kelvin>::new(5.0)).value, 15.0));
+        // 9 °F of difference is 5 K, whatever the zero of the Fahrenheit scale
+        let warmer = start + Value::<f64, Delta<Fahrenheit>>::new(9.0);
+        assert!(close(warmer.value, 25.0));
+
+        let mut reading = Point::<f64, Kelvin>::new(300.0);
+        reading += Value::<f64, Delta<Celsius>>::new(2.0);
+        reading -= Value::<f64, Delta<Fahrenheit>>::new(18.0);
+        assert!(close(reading.value, 292.0));
+    }
+}
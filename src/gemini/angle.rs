@@ -0,0 +1,67 @@
+use crate::dim;
+use crate::gemini::units::{Angle, Radian};
+
+use super::{ConvertibleToQuantityBaseUnit, Quantity, Unit, Value};
+
+// Trigonometry on angle values in any unit: Value::<f64, Degree>::new(30.0).sin() == 0.5.
+// Inverse functions return radians, convert for other units.
+impl<V, U> Value<V, U>
+where
+    V: Copy + Into<f64> + From<f64>,
+    U: Unit,
+    U::Quantity: Quantity<Dimension = dim::base::A, Kind = Angle>,
+    U::Base: ConvertibleToQuantityBaseUnit,
+{
+    fn radians(self) -> f64 {
+        self.convert::<Radian>().value.into()
+    }
+
+    pub fn sin(self) -> V {
+        V::from(self.radians().sin())
+    }
+
+    pub fn cos(self) -> V {
+        V::from(self.radians().cos())
+    }
+
+    pub fn tan(self) -> V {
+        V::from(self.radians().tan())
+    }
+}
+
+impl<V: Copy + Into<f64> + From<f64>> Value<V, Radian> {
+    pub fn asin(ratio: V) -> Self {
+        Value::new(V::from(ratio.into().asin()))
+    }
+
+    pub fn acos(ratio: V) -> Self {
+        Value::new(V::from(ratio.into().acos()))
+    }
+
+    pub fn atan(ratio: V) -> Self {
+        Value::new(V::from(ratio.into().atan()))
+    }
+}
+
+impl<V, U> Value<V, U>
+where
+    V: Copy + Into<f64> + From<f64>,
+    U: Unit,
+    U::Base: ConvertibleToQuantityBaseUnit,
+{
+    // Angle of the vector (x, y), with self as the y component; x may be in any unit of the
+    // same quantity, e.g. a height in feet over a distance in meters.
+    pub fn atan2<UX>(self, x: Value<V, UX>) -> Value<V, Radian>
+    where
+        UX: Unit,
+        UX::Quantity: Quantity<
+            Dimension = <U::Quantity as Quantity>::Dimension,
+            Kind = <U::Quantity as Quantity>::Kind,
+        >,
+        UX::Base: ConvertibleToQuantityBaseUnit,
+    {
+        let y: f64 = self.value.into();
+        let x: f64 = x.convert::<U>().value.into();
+        Value::new(V::from(y.atan2(x)))
+    }
+}
@@ -0,0 +1,101 @@
+use std::fmt::Display;
+
+use crate::gemini::units::{
+    Coulomb, CubicMeter, Joule, Kelvin, Kilogram, MeterPerSecond, MeterPerSecondSquared, Mole,
+    Second,
+};
+use crate::gemini::{One, Unit, UnitDiv, UnitMul, Value};
+
+// Physical constants (CODATA 2018). Since the 2019 SI redefinition c, h, e, k_B and N_A are exact
+// by definition; measured constants carry their standard uncertainty in the same unit.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Constant<U> {
+    pub value: Value<f64, U>,
+    pub uncertainty: Option<f64>, // None for exact constants
+    pub symbol: &'static str,
+}
+
+impl<U> Constant<U> {
+    pub const fn is_exact(&self) -> bool {
+        self.uncertainty.is_none()
+    }
+}
+
+impl<U: Unit> Display for Constant<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.uncertainty {
+            None => write!(
+                f,
+                "{} = {:e} {} (exact)",
+                self.symbol,
+                self.value.value,
+                U::default()
+            ),
+            Some(u) => write!(
+                f,
+                "{} = ({:e} ± {:e}) {}",
+                self.symbol,
+                self.value.value,
+                u,
+                U::default()
+            ),
+        }
+    }
+}
+
+pub type JouleSecond = UnitMul<Joule, Second>;
+pub type JoulePerKelvin = UnitDiv<Joule, Kelvin>;
+pub type PerMole = UnitDiv<One, Mole>;
+pub type CubicMeterPerKilogramSecondSquared =
+    UnitDiv<CubicMeter, UnitMul<Kilogram, UnitMul<Second, Second>>>;
+
+// Speed of light in vacuum
+pub const SPEED_OF_LIGHT: Constant<MeterPerSecond> = Constant {
+    value: Value::new(299_792_458.0),
+    uncertainty: None,
+    symbol: "c",
+};
+
+pub const PLANCK: Constant<JouleSecond> = Constant {
+    value: Value::new(6.626_070_15e-34),
+    uncertainty: None,
+    symbol: "h",
+};
+
+// h / 2π, exact because h is
+pub const REDUCED_PLANCK: Constant<JouleSecond> = Constant {
+    value: Value::new(6.626_070_15e-34 / (2.0 * std::f64::consts::PI)),
+    uncertainty: None,
+    symbol: "ħ",
+};
+
+pub const BOLTZMANN: Constant<JoulePerKelvin> = Constant {
+    value: Value::new(1.380_649e-23),
+    uncertainty: None,
+    symbol: "k_B",
+};
+
+pub const AVOGADRO: Constant<PerMole> = Constant {
+    value: Value::new(6.022_140_76e23),
+    uncertainty: None,
+    symbol: "N_A",
+};
+
+pub const ELEMENTARY_CHARGE: Constant<Coulomb> = Constant {
+    value: Value::new(1.602_176_634e-19),
+    uncertainty: None,
+    symbol: "e",
+};
+
+pub const GRAVITATIONAL: Constant<CubicMeterPerKilogramSecondSquared> = Constant {
+    value: Value::new(6.674_30e-11),
+    uncertainty: Some(0.000_15e-11),
+    symbol: "G",
+};
+
+// Standard acceleration of gravity, exact by convention (3rd CGPM, 1901)
+pub const STANDARD_GRAVITY: Constant<MeterPerSecondSquared> = Constant {
+    value: Value::new(9.806_65),
+    uncertainty: None,
+    symbol: "g₀",
+};
@@ -0,0 +1,250 @@
+use std::fmt::Display;
+use std::ops::{Add, Neg, Sub};
+
+use super::{ConvertibleToQuantityBaseUnit, One, Quantity, Unit, Value};
+
+// Logarithmic units express a linear value relative to a reference:
+//   level = MULTIPLIER * log_BASE(linear / REFERENCE)
+// e.g. dBm = 10 * log10(P / 1 mW), dB SPL = 20 * log10(p / 20 µPa), pH = -log10(a / 1 mol/L)
+pub trait LogUnit: Copy + Clone + Default + Display {
+    // Unit the reference (and the linear value) is expressed in.
+    type Linear: Unit;
+    // Gain for ratios of dimensionless values (dB, Np), Absolute for levels of a quantity (dBm).
+    // Decides what subtracting a level of this unit means, see LevelSub.
+    type Role;
+    const REFERENCE: f64;
+    const BASE: f64;
+    const MULTIPLIER: f64;
+    // Root-power (field) quantities like voltage or pressure enter power ratios squared.
+    const ROOT_POWER: bool;
+}
+
+// Roles of log units
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Gain;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Absolute;
+
+// Size of one step of L in decibels of power ratio (1 for dB, 8.686 for Np, -10 for pH).
+fn decibels_per_unit<L: LogUnit>() -> f64 {
+    let power_exponent = if L::ROOT_POWER { 2.0 } else { 1.0 };
+    10.0 * power_exponent * L::BASE.log10() / L::MULTIPLIER
+}
+
+// Level
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub struct Level<V, L> {
+    pub value: V,
+    _unit: std::marker::PhantomData<L>,
+}
+
+impl<V, L> Level<V, L> {
+    // A constructor for creating a new Level.
+    pub const fn new(value: V) -> Self {
+        Level {
+            value,
+            _unit: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<V, L> Level<V, L>
+where
+    V: Copy + Into<f64> + From<f64>,
+    L: LogUnit,
+{
+    pub fn to_linear(self) -> Value<V, L::Linear> {
+        let ratio = L::BASE.powf(self.value.into() / L::MULTIPLIER);
+        Value::new(V::from(L::REFERENCE * ratio))
+    }
+
+    // Level of a positive linear value; None for zero, negative or NaN values, which have no
+    // logarithm
+    pub fn from_linear<U: Unit>(value: Value<V, U>) -> Option<Self>
+    where
+        <L::Linear as Unit>::Quantity: Quantity<
+            Dimension = <U::Quantity as Quantity>::Dimension,
+            Kind = <U::Quantity as Quantity>::Kind,
+        >,
+        U::Base: ConvertibleToQuantityBaseUnit,
+        <L::Linear as Unit>::Base: ConvertibleToQuantityBaseUnit,
+    {
+        let linear: f64 = value.convert::<L::Linear>().value.into();
+        (linear > 0.0).then(|| Level::new(V::from(level::<L>(linear))))
+    }
+
+    pub fn convert<TargetL: LogUnit>(self) -> Level<V, TargetL>
+    where
+        <TargetL::Linear as Unit>::Quantity: Quantity<
+            Dimension = <<L::Linear as Unit>::Quantity as Quantity>::Dimension,
+            Kind = <<L::Linear as Unit>::Quantity as Quantity>::Kind,
+        >,
+        <L::Linear as Unit>::Base: ConvertibleToQuantityBaseUnit,
+        <TargetL::Linear as Unit>::Base: ConvertibleToQuantityBaseUnit,
+    {
+        let linear: f64 = self.to_linear().convert::<TargetL::Linear>().value.into();
+        Level::new(V::from(level::<TargetL>(linear)))
+    }
+}
+
+fn level<L: LogUnit>(linear: f64) -> f64 {
+    L::MULTIPLIER * (linear / L::REFERENCE).log(L::BASE)
+}
+
+// Adding a gain (a level relative to a dimensionless reference) multiplies the linear value.
+impl<V, L, G> Add<Level<V, G>> for Level<V, L>
+where
+    V: Copy + Into<f64> + From<f64>,
+    L: LogUnit,
+    G: LogUnit<Linear = One>,
+{
+    type Output = Level<V, L>;
+    fn add(self, rhs: Level<V, G>) -> Self::Output {
+        let gain = rhs.value.into() * decibels_per_unit::<G>() / decibels_per_unit::<L>();
+        Level::new(V::from(self.value.into() + gain))
+    }
+}
+
+// Subtracting a gain divides the linear value (output - 3 dB); the difference of two levels of
+// the same quantity is the gain between them, in dB. Which one applies depends on the role of
+// the right-hand side's unit, a gain or an absolute level.
+pub trait LevelSub<V, LA, LB> {
+    type Output;
+    fn sub(lhs: Level<V, LA>, rhs: Level<V, LB>) -> Self::Output;
+}
+
+impl<V, L, G> LevelSub<V, L, G> for Gain
+where
+    V: Copy + Into<f64> + From<f64>,
+    L: LogUnit,
+    G: LogUnit<Linear = One>,
+{
+    type Output = Level<V, L>;
+    fn sub(lhs: Level<V, L>, rhs: Level<V, G>) -> Self::Output {
+        let gain = rhs.value.into() * decibels_per_unit::<G>() / decibels_per_unit::<L>();
+        Level::new(V::from(lhs.value.into() - gain))
+    }
+}
+
+impl<V, LA, LB> LevelSub<V, LA, LB> for Absolute
+where
+    V: Copy + Into<f64> + From<f64>,
+    LA: LogUnit,
+    LB: LogUnit,
+    <LB::Linear as Unit>::Quantity: Quantity<
+        Dimension = <<LA::Linear as Unit>::Quantity as Quantity>::Dimension,
+        Kind = <<LA::Linear as Unit>::Quantity as Quantity>::Kind,
+    >,
+    <LA::Linear as Unit>::Base: ConvertibleToQuantityBaseUnit,
+    <LB::Linear as Unit>::Base: ConvertibleToQuantityBaseUnit,
+{
+    type Output = Level<V, Decibel>;
+    fn sub(lhs: Level<V, LA>, rhs: Level<V, LB>) -> Self::Output {
+        let lhs: f64 = lhs.to_linear().value.into();
+        let rhs: f64 = rhs.to_linear().convert::<LA::Linear>().value.into();
+        let power_exponent = if LA::ROOT_POWER { 2.0 } else { 1.0 };
+        Level::new(V::from(10.0 * power_exponent * (lhs / rhs).log10()))
+    }
+}
+
+impl<V, LA, LB> Sub<Level<V, LB>> for Level<V, LA>
+where
+    LB: LogUnit,
+    LB::Role: LevelSub<V, LA, LB>,
+{
+    type Output = <LB::Role as LevelSub<V, LA, LB>>::Output;
+    fn sub(self, rhs: Level<V, LB>) -> Self::Output {
+        LB::Role::sub(self, rhs)
+    }
+}
+
+impl<V, G> Neg for Level<V, G>
+where
+    V: Neg<Output = V>,
+    G: LogUnit<Linear = One>,
+{
+    type Output = Level<V, G>;
+    fn neg(self) -> Self::Output {
+        Level::new(-self.value)
+    }
+}
+
+impl<V, L> Display for Level<V, L>
+where
+    V: Display,
+    L: LogUnit,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.value, L::default())
+    }
+}
+
+// Dimensionless gains
+crate::log_unit!(gain Decibel, base: 10.0, multiplier: 10.0, root_power: false, "dB");
+crate::log_unit!(gain Bel, base: 10.0, multiplier: 1.0, root_power: false, "B");
+crate::log_unit!(gain Neper, base: std::f64::consts::E, multiplier: 1.0, root_power: true, "Np");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gemini::units::{
+        DecibelMilliwatt, DecibelSpl, DecibelVolt, DecibelWatt, MolePerLiter, Pascal, Volt, Watt,
+        PH,
+    };
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-9 * b.abs().max(1.0)
+    }
+
+    #[test]
+    fn levels_convert_to_and_from_linear() {
+        assert!(close(
+            Level::<f64, DecibelMilliwatt>::new(30.0)
+                .to_linear()
+                .convert::<Watt>()
+                .value,
+            1.0
+        ));
+        let level =
+            Level::<f64, DecibelMilliwatt>::from_linear(Value::<f64, Watt>::new(0.1)).unwrap();
+        assert!(close(level.value, 20.0));
+        assert!(close(level.convert::<DecibelWatt>().value, -10.0));
+
+        let loud = Level::<f64, DecibelSpl>::from_linear(Value::<f64, Pascal>::new(2.0)).unwrap();
+        assert!(close(loud.value, 100.0));
+        let volts = Level::<f64, DecibelVolt>::new(20.0).to_linear();
+        assert!(close(volts.convert::<Volt>().value, 10.0));
+
+        let neutral = Level::<f64, PH>::from_linear(Value::<f64, MolePerLiter>::new(1e-7)).unwrap();
+        assert!(close(neutral.value, 7.0));
+        // No logarithm of nothing
+        assert!(Level::<f64, DecibelWatt>::from_linear(Value::<f64, Watt>::new(0.0)).is_none());
+        assert!(Level::<f64, DecibelVolt>::from_linear(Value::<f64, Volt>::new(-1.0)).is_none());
+        assert_eq!(
+            Level::<f64, DecibelMilliwatt>::new(3.0).to_string(),
+            "3 dBm"
+        );
+    }
+
+    #[test]
+    fn gains_add_and_levels_subtract() {
+        let output = Level::<f64, DecibelMilliwatt>::new(10.0) + Level::<f64, Decibel>::new(3.0);
+        assert!(close(output.value, 13.0));
+        let attenuated = output - Level::<f64, Decibel>::new(13.0);
+        assert!(close(attenuated.value, 0.0));
+        let attenuated = output - Level::<f64, Bel>::new(0.3);
+        assert!(close(attenuated.value, 10.0));
+        // 1 B = 10 dB, 1 Np = 20 log10(e) dB of power ratio
+        let output = Level::<f64, DecibelMilliwatt>::new(0.0) + Level::<f64, Bel>::new(1.0);
+        assert!(close(output.value, 10.0));
+        let output = Level::<f64, DecibelMilliwatt>::new(0.0) + Level::<f64, Neper>::new(1.0);
+        assert!(close(output.value, 20.0 * std::f64::consts::E.log10()));
+
+        let gain = Level::<f64, DecibelWatt>::new(0.0) - Level::<f64, DecibelMilliwatt>::new(0.0);
+        assert!(close(gain.value, 30.0));
+        // Field quantities: a tenfold voltage is 20 dB
+        let gain = Level::<f64, DecibelVolt>::new(20.0) - Level::<f64, DecibelVolt>::new(0.0);
+        assert!(close(gain.value, 20.0));
+    }
+}
@@ -0,0 +1,38 @@
+// Prefix types for PrefixedUnit, e.g. PrefixedUnit<Kilo, Meter>.
+// The SI ones convert to and from temp::prefixes::SIPrefix.
+
+// SI prefixes (including the 2022 additions ronna, quetta, ronto and quecto)
+crate::prefix!(Quetta, si: Quetta, 1e30, "Q");
+crate::prefix!(Ronna, si: Ronna, 1e27, "R");
+crate::prefix!(Yotta, si: Yotta, 1e24, "Y");
+crate::prefix!(Zetta, si: Zetta, 1e21, "Z");
+crate::prefix!(Exa, si: Exa, 1e18, "E");
+crate::prefix!(Peta, si: Peta, 1e15, "P");
+crate::prefix!(Tera, si: Tera, 1e12, "T");
+crate::prefix!(Giga, si: Giga, 1e9, "G");
+crate::prefix!(Mega, si: Mega, 1e6, "M");
+crate::prefix!(Kilo, si: Kilo, 1e3, "k");
+crate::prefix!(Hecto, si: Hecto, 1e2, "h");
+crate::prefix!(Deca, si: Deca, 1e1, "da");
+crate::prefix!(Deci, si: Deci, 1e-1, "d");
+crate::prefix!(Centi, si: Centi, 1e-2, "c");
+crate::prefix!(Milli, si: Milli, 1e-3, "m");
+crate::prefix!(Micro, si: Micro, 1e-6, "μ");
+crate::prefix!(Nano, si: Nano, 1e-9, "n");
+crate::prefix!(Pico, si: Pico, 1e-12, "p");
+crate::prefix!(Femto, si: Femto, 1e-15, "f");
+crate::prefix!(Atto, si: Atto, 1e-18, "a");
+crate::prefix!(Zepto, si: Zepto, 1e-21, "z");
+crate::prefix!(Yocto, si: Yocto, 1e-24, "y");
+crate::prefix!(Ronto, si: Ronto, 1e-27, "r");
+crate::prefix!(Quecto, si: Quecto, 1e-30, "q");
+
+// IEC binary prefixes
+crate::prefix!(Kibi, 1024.0, "Ki");
+crate::prefix!(Mebi, 1_048_576.0, "Mi");
+crate::prefix!(Gibi, 1_073_741_824.0, "Gi");
+crate::prefix!(Tebi, 1_099_511_627_776.0, "Ti");
+crate::prefix!(Pebi, 1_125_899_906_842_624.0, "Pi");
+crate::prefix!(Exbi, 1_152_921_504_606_846_976.0, "Ei");
+crate::prefix!(Zebi, 1_180_591_620_717_411_303_424.0, "Zi");
+crate::prefix!(Yobi, 1_208_925_819_614_629_174_706_176.0, "Yi");
@@ -0,0 +1,182 @@
+// Unit catalog: the seven SI base quantities, angles, the 22 derived quantities with special
+// names, US customary and Imperial units, information and a few common logarithmic units.
+// Mirrors temp::units for the runtime API.
+use crate::gemini::prefixes::{Centi, Gibi, Kibi, Kilo, Mebi, Milli};
+use crate::gemini::PrefixedUnit;
+
+// Base quantities
+crate::quantity!(Length, dim: L, Meter, "m");
+crate::quantity!(Mass, dim: M, (Kilogram, Kilo, Gram), "g");
+crate::quantity!(Time, dim: T, Second, "s");
+crate::quantity!(ElectricCurrent, dim: I, Ampere, "A");
+crate::quantity!(Temperature, dim: Th, Kelvin, "K");
+crate::quantity!(AmountOfSubstance, dim: N, Mole, "mol");
+crate::quantity!(LuminousIntensity, dim: J, Candela, "cd");
+
+// Affine temperature units
+crate::unit!(Celsius, Temperature, 1.0, offset: 273.15, "°C");
+
+// Geometry and kinematics
+crate::quantity!(Area, [(Length, 2)], SquareMeter, "m²");
+crate::quantity!(Volume, [(Length, 3)], CubicMeter, "m³");
+crate::quantity!(Velocity, [(Length, 1), (Time, -1)], MeterPerSecond, "m/s");
+crate::quantity!(
+    Acceleration,
+    [(Velocity, 1), (Time, -1)],
+    MeterPerSecondSquared,
+    "m/s²"
+);
+crate::quantity!(
+    Concentration,
+    [(AmountOfSubstance, 1), (Length, -3)],
+    MolePerCubicMeter,
+    "mol/m³"
+);
+
+// Non-SI units accepted for use with the SI
+crate::unit!(Minute, 60.0 * Second, "min");
+crate::unit!(Hour, 60.0 * Minute, "h");
+crate::unit!(Liter, Volume, 1e-3, "L");
+
+// Plane and solid angle, dimensionless unless the angle-dimension feature is enabled.
+// Plane and solid angle and angular velocity are kinds of their own so rpm does not silently
+// convert to Hz, nor sr to rad or rad to the dimensionless one.
+crate::quantity!(kind Angle = "angle", dim: A, Radian, "rad");
+crate::quantity!(kind SolidAngle = "solid angle", [(Angle, 2)], Steradian, "sr");
+crate::unit!(Degree, Angle, std::f64::consts::PI / 180.0, "°");
+crate::unit!(Gradian, Angle, std::f64::consts::PI / 200.0, "gon");
+crate::unit!(Arcminute, (1.0 / 60.0) * Degree, "′");
+crate::unit!(Arcsecond, (1.0 / 60.0) * Arcminute, "″");
+crate::unit!(Turn, Angle, std::f64::consts::TAU, "tr");
+crate::quantity!(
+    kind AngularVelocity = "angular velocity",
+    [(Angle, 1), (Time, -1)],
+    RadianPerSecond,
+    "rad/s"
+);
+crate::unit!(
+    RevolutionPerMinute,
+    AngularVelocity,
+    std::f64::consts::TAU / 60.0,
+    "rpm"
+);
+
+// Derived quantities with special names. Activity, equivalent dose and torque are kinds of
+// their own: Bq, Sv and N·m do not convert to Hz, Gy and J without an explicit as_kind.
+crate::quantity!(Frequency, [(Time, -1)], Hertz, "Hz");
+crate::quantity!(Force, [(Mass, 1), (Acceleration, 1)], Newton, "N");
+crate::quantity!(Pressure, [(Force, 1), (Area, -1)], Pascal, "Pa");
+crate::quantity!(Energy, [(Force, 1), (Length, 1)], Joule, "J");
+crate::quantity!(kind Torque = "torque", [(Force, 1), (Length, 1)], NewtonMeter, "N·m");
+crate::quantity!(Power, [(Energy, 1), (Time, -1)], Watt, "W");
+crate::quantity!(
+    ElectricCharge,
+    [(ElectricCurrent, 1), (Time, 1)],
+    Coulomb,
+    "C"
+);
+crate::quantity!(Voltage, [(Power, 1), (ElectricCurrent, -1)], Volt, "V");
+crate::quantity!(
+    Capacitance,
+    [(ElectricCharge, 1), (Voltage, -1)],
+    Farad,
+    "F"
+);
+crate::quantity!(Resistance, [(Voltage, 1), (ElectricCurrent, -1)], Ohm, "Ω");
+crate::quantity!(Conductance, [(Resistance, -1)], Siemens, "S");
+crate::quantity!(MagneticFlux, [(Voltage, 1), (Time, 1)], Weber, "Wb");
+crate::quantity!(
+    MagneticFluxDensity,
+    [(MagneticFlux, 1), (Area, -1)],
+    Tesla,
+    "T"
+);
+crate::quantity!(
+    Inductance,
+    [(MagneticFlux, 1), (ElectricCurrent, -1)],
+    Henry,
+    "H"
+);
+// With dimensionless angles lm = cd·sr has the dimension of cd, and lx that of luminance (cd/m²)
+crate::quantity!(
+    kind LuminousFlux = "luminous flux",
+    [(LuminousIntensity, 1), (SolidAngle, 1)],
+    Lumen,
+    "lm"
+);
+crate::quantity!(kind Illuminance = "illuminance", [(LuminousFlux, 1), (Area, -1)], Lux, "lx");
+crate::quantity!(kind Activity = "activity", [(Time, -1)], Becquerel, "Bq");
+crate::quantity!(AbsorbedDose, [(Energy, 1), (Mass, -1)], Gray, "Gy");
+crate::quantity!(kind EquivalentDose = "equivalent dose", [(Energy, 1), (Mass, -1)], Sievert, "Sv");
+crate::quantity!(
+    CatalyticActivity,
+    [(AmountOfSubstance, 1), (Time, -1)],
+    Katal,
+    "kat"
+);
+
+// US customary and Imperial units, exact by definition
+// (international yard and pound agreement of 1959: 1 in = 2.54 cm, 1 lb = 0.45359237 kg)
+pub type Centimeter = PrefixedUnit<Centi, Meter>;
+crate::unit!(Inch, 2.54 * Centimeter, "in");
+crate::unit!(Foot, 12.0 * Inch, "ft");
+crate::unit!(Yard, 3.0 * Foot, "yd");
+crate::unit!(Mile, 5280.0 * Foot, "mi");
+crate::unit!(SurveyFoot, (1200.0 / 3937.0) * Meter, "ft_survey");
+crate::unit!(SurveyMile, 5280.0 * SurveyFoot, "mi_survey");
+crate::unit!(Gallon, Volume, 3.785411784e-3, "gal");
+crate::unit!(Quart, 0.25 * Gallon, "qt");
+crate::unit!(Pint, 0.5 * Quart, "pt");
+crate::unit!(FluidOunce, (1.0 / 16.0) * Pint, "fl_oz");
+crate::unit!(ImperialGallon, Volume, 4.54609e-3, "gal_imp");
+crate::unit!(ImperialQuart, 0.25 * ImperialGallon, "qt_imp");
+crate::unit!(ImperialPint, 0.5 * ImperialQuart, "pt_imp");
+crate::unit!(ImperialFluidOunce, (1.0 / 20.0) * ImperialPint, "fl_oz_imp");
+crate::unit!(Pound, 0.45359237 * Kilogram, "lb");
+crate::unit!(Ounce, (1.0 / 16.0) * Pound, "oz");
+crate::unit!(Stone, 14.0 * Pound, "st");
+crate::unit!(ShortTon, 2000.0 * Pound, "ton");
+crate::unit!(LongTon, 2240.0 * Pound, "ton_imp");
+crate::unit!(Slug, Mass, 0.45359237 * 9.80665 / 0.3048, "slug");
+crate::unit!(PoundForce, Force, 0.45359237 * 9.80665, "lbf");
+crate::unit!(
+    Psi,
+    Pressure,
+    0.45359237 * 9.80665 / (0.0254 * 0.0254),
+    "psi"
+);
+crate::unit!(Btu, Energy, 1055.05585262, "BTU");
+crate::unit!(
+    Horsepower,
+    Power,
+    550.0 * 0.3048 * 0.45359237 * 9.80665,
+    "hp"
+);
+crate::unit!(MetricHorsepower, Power, 75.0 * 9.80665, "PS");
+crate::unit!(ElectricalHorsepower, Power, 746.0, "hp_E");
+
+// Information, a dimensionless count of bits. Information and data rate are kinds of their own,
+// so bytes do not convert to radians nor bit/s to Hz.
+crate::quantity!(kind Information = "information", [], Bit, "bit");
+crate::unit!(Nibble, 4.0 * Bit, "nibble");
+crate::unit!(Byte, 8.0 * Bit, "B");
+crate::unit!(Octet, 8.0 * Bit, "o");
+pub type Kibibyte = PrefixedUnit<Kibi, Byte>;
+pub type Mebibyte = PrefixedUnit<Mebi, Byte>;
+pub type Gibibyte = PrefixedUnit<Gibi, Byte>;
+crate::quantity!(
+    kind DataRate = "data rate",
+    [(Information, 1), (Time, -1)],
+    BitPerSecond,
+    "bit/s"
+);
+crate::unit!(BytePerSecond, DataRate, 8.0, "B/s");
+
+// Logarithmic units
+crate::unit!(MolePerLiter, Concentration, 1000.0, "mol/L");
+pub type Milliwatt = PrefixedUnit<Milli, Watt>;
+crate::log_unit!(DecibelMilliwatt, Milliwatt, 1.0, power, "dBm");
+crate::log_unit!(DecibelWatt, Watt, 1.0, power, "dBW");
+crate::log_unit!(DecibelVolt, Volt, 1.0, root_power, "dBV");
+crate::log_unit!(DecibelSpl, Pascal, 20e-6, root_power, "dB SPL");
+crate::log_unit!(PH, MolePerLiter, reference: 1.0, base: 10.0, multiplier: -1.0, root_power: false, "pH");
@@ -0,0 +1,4 @@
+pub mod dim;
+pub mod gemini;
+pub mod temp;
+pub mod typed;
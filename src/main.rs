@@ -1,16 +1,7 @@
-// mod prefixes;
-// mod quantity;
-mod gemini;
-// mod unit;
-// mod units;
-// mod value;
-
-// use crate::quantity::QUANTITY_REGISTRY;
-// use crate::units::{HORSEPOWER, KILOGRAM, METER, NEWTON, SECOND};
-// use crate::value::Value;
-// use crate::quantity::{EncodedDimensionVector, Quantity};
-
-use crate::gemini::{PrefixedUnit, Quantity, Unit, Value};
+use ferrunitas::dim::Dimension;
+use ferrunitas::gemini::prefixes::{Kilo, Mega, Milli};
+use ferrunitas::gemini::{Decibel, Level, Neper, Point, PrefixedUnit, Quantity, Unit, Value};
+use ferrunitas::{quantity, unit};
 
 fn print_unit_details<U: Unit>() {
     println!("--- Details for {} ---", std::any::type_name::<U>());
@@ -21,19 +12,14 @@ fn print_unit_details<U: Unit>() {
 }
 
 fn mass() {
-    prefix!(Kilo, 1000.0, "k");
-    prefix!(Mega, 1000000.0, "M");
-    prefix!(Milli, 0.001, "m");
-    quantity!(Mass, (Kilogram, Kilo, Gram), "g");
-    unit!(Pound, Mass, 0.45359237, "lbs");
-    unit!(Stone, Mass, 6.35029, "st");
+    use ferrunitas::gemini::units::{Kilogram, Mass, Pound, Stone};
     type Megapound = PrefixedUnit<Mega, Pound>;
     type Millistone = PrefixedUnit<Milli, Stone>;
 
     // Mass::BaseUnit = Kilogram
     // Kilogram::Base = Gram
     // Gram::Base = Gram
-    // Pound::Base = Kilogram
+    // Pound::Base = Pound, defined as 0.45359237 Kilogram
     // Megapound::Base = Pound
 
     let mst: Value<f64, Millistone> = Value::new(212431230.0);
@@ -41,6 +27,10 @@ fn mass() {
     println!("{} = {}", mst, lb);
     println!("{}", mst.value / 1000.0 * 6.35029 * 2.20462 / 1000000.0);
 
+    let mut total: Value<f64, Kilogram> = Value::new(2.0);
+    total += Value::<f64, Pound>::new(1.0);
+    println!("{}", total - Value::<f64, Stone>::new(0.1) + -mst);
+
     let kg: Value<_, Kilogram> = mst.convert();
     println!("{} = {}", mst, kg);
     println!("{}", mst.value / 1000.0 * 6.35029);
@@ -49,7 +39,7 @@ fn mass() {
         "Quantity  Base: {}\n",
         std::any::type_name::<<Mass as Quantity>::BaseUnit>()
     );
-    // print_unit_details::<Millistone>();
+    print_unit_details::<Millistone>();
     // print_unit_details::<Stone>();
     // print_unit_details::<Kilogram>();
     // print_unit_details::<Gram>();
@@ -58,8 +48,7 @@ fn mass() {
 }
 
 fn time() {
-    prefix!(Milli, 0.001, "m");
-    quantity!(Time, Second, "s");
+    quantity!(Time, dim: T, Second, "s");
     unit!(Minute, Time, 60.0, "min");
     unit!(Hour, Time, 3600.0, "h");
     type Millisecond = PrefixedUnit<Milli, Second>;
@@ -72,6 +61,747 @@ fn time() {
     println!("{} = {}", h, min);
 }
 
+fn force() {
+    quantity!(Length, dim: L, Meter, "m");
+    quantity!(Mass, dim: M, (Kilogram, Kilo, Gram), "g");
+    quantity!(Time, dim: T, Second, "s");
+    quantity!(Velocity, [(Length, 1), (Time, -1)], MeterPerSecond, "m/s");
+    quantity!(
+        Acceleration,
+        [(Velocity, 1), (Time, -1)],
+        MeterPerSecondSquared,
+        "m/s²"
+    );
+    quantity!(Force, [(Mass, 1), (Acceleration, 1)], Newton, "N");
+    quantity!(
+        Weight,
+        [(Mass, 1), (Length, 1), (Time, -2)],
+        WeightNewton,
+        "N"
+    );
+    unit!(PoundForce, Force, 4.4482216152605, "lbf");
+
+    println!(
+        "Force: {:?}, Mass·Length/Time²: {:?}",
+        <Force as Quantity>::Dimension::EXPONENTS,
+        <Weight as Quantity>::Dimension::EXPONENTS
+    );
+
+    // Same dimension, so the conversion typechecks
+    let lbf: Value<f64, PoundForce> = Value::new(10.0);
+    let n: Value<f64, WeightNewton> = lbf.convert();
+    println!("{} = {}", lbf, n);
+
+    // Products and quotients carry the derived unit
+    type Millimeter = PrefixedUnit<Milli, Meter>;
+    let m: Value<f64, Kilogram> = Value::new(0.5);
+    let d: Value<f64, Millimeter> = Value::new(9810.0);
+    let t: Value<f64, Second> = Value::new(1.0);
+    let a = d / t / t;
+    let f = m * a;
+    let f_n: Value<f64, Newton> = f.convert();
+    println!("{} * {} = {} = {}", m, a, f, f_n);
+    println!("{}", f * 2.0);
+}
+
+fn temperature() {
+    quantity!(Temperature, dim: Th, Kelvin, "K");
+    unit!(Celsius, Temperature, 1.0, offset: 273.15, "°C");
+    unit!(Fahrenheit, Temperature, 5.0 / 9.0, offset: 459.67 * 5.0 / 9.0, "°F");
+
+    let c: Value<f64, Celsius> = Value::new(100.0);
+    let f: Value<f64, Fahrenheit> = c.convert();
+    let k: Value<f64, Kelvin> = f.convert();
+    println!("{} = {} = {}", c, f, k);
+
+    // Readings are points, their differences are intervals
+    let morning: Point<f64, Celsius> = Point::new(12.0);
+    let noon: Point<f64, Fahrenheit> = Point::new(77.0);
+    let warming = noon - morning;
+    println!("{} - {} = {}", noon, morning, warming);
+    println!("{} + {} = {}", morning, warming, morning + warming);
+
+    let body = ferrunitas::temp::value::Value {
+        value: 98.6,
+        unit: ferrunitas::temp::units::FAHRENHEIT,
+    };
+    let body_c = body.convert_to(ferrunitas::temp::units::CELSIUS).unwrap();
+    println!("{} °F = {} °C", body.value, body_c.value);
+}
+
+fn levels() {
+    use ferrunitas::gemini::units::{
+        DecibelMilliwatt, DecibelSpl, DecibelVolt, MolePerCubicMeter, Pascal, Volt, PH,
+    };
+
+    let tx: Level<f64, DecibelMilliwatt> = Level::new(20.0);
+    let rx = tx + Level::<f64, Decibel>::new(-3.0) - Level::<f64, Neper>::new(1.0);
+    println!("{} -> {} = {}, loss {}", tx, rx, rx.to_linear(), rx - tx);
+
+    let signal: Value<f64, Volt> = Value::new(0.5);
+    let talk: Value<f64, Pascal> = Value::new(0.02);
+    if let (Some(signal_level), Some(talk_level)) = (
+        Level::<f64, DecibelVolt>::from_linear(signal),
+        Level::<f64, DecibelSpl>::from_linear(talk),
+    ) {
+        println!("{} = {}, {} = {}", signal, signal_level, talk, talk_level);
+    }
+
+    let acid: Level<f64, PH> = Level::new(3.0);
+    println!(
+        "{} = {}",
+        acid,
+        acid.to_linear().convert::<MolePerCubicMeter>()
+    );
+}
+
+fn parsing() {
+    use ferrunitas::temp::parse::parse_unit;
+    use ferrunitas::temp::unit::Unit as RuntimeUnit;
+
+    for expr in [
+        "kg·m/s^2",
+        "km/h",
+        "N m-2",
+        "(mm/s)²",
+        "1/min",
+        "kg/(m*s**2)",
+        "°C",
+    ] {
+        let mut unit: RuntimeUnit = expr.parse().unwrap();
+        println!("{} -> {} x {}", expr, unit.repr(), unit.to_si(1.0));
+    }
+    for expr in ["kg·m/sx^2", "km//h", "(m/s", "m^", "m^(1/0)", "√"] {
+        println!("{} -> {}", expr, parse_unit(expr).unwrap_err());
+    }
+
+    quantity!(Length, dim: L, Meter, "m");
+    quantity!(Time, dim: T, Second, "s");
+    quantity!(Velocity, [(Length, 1), (Time, -1)], MeterPerSecond, "m/s");
+    for literal in ["5.3 km/h", "-12e3 mm/s", "1,5 m/s", "30 s", "1e2"] {
+        let value: Result<Value<f64, MeterPerSecond>, _> = literal.parse();
+        match value {
+            Ok(v) => println!("{} -> {}", literal, v),
+            Err(e) => println!("{} -> {}", literal, e),
+        }
+    }
+    let timeout = ferrunitas::temp::parse::parse_value(" 30 min").unwrap();
+    println!(
+        "{} {} = {} s",
+        timeout.value,
+        timeout.unit.symbol,
+        timeout.to_si()
+    );
+}
+
+fn prefixes() {
+    use ferrunitas::gemini::prefixes::Quetta;
+    use ferrunitas::temp::format::{format_prefixed, PrefixFormat};
+    use ferrunitas::temp::prefixes::SIPrefix;
+    use ferrunitas::temp::units::{GRAM, HOUR, METER, NEWTON};
+    use ferrunitas::temp::value::Value as RuntimeValue;
+
+    for value in [
+        RuntimeValue {
+            value: 0.0012,
+            unit: METER,
+        },
+        RuntimeValue {
+            value: 4_250_000.0,
+            unit: NEWTON,
+        },
+        RuntimeValue {
+            value: 1500.0,
+            unit: GRAM,
+        },
+        RuntimeValue {
+            value: 0.1 + 0.2,
+            unit: METER,
+        },
+        RuntimeValue {
+            value: 36.0,
+            unit: HOUR,
+        },
+    ] {
+        println!(
+            "{} {} -> {}",
+            value.value,
+            value.unit.symbol,
+            value.prefixed()
+        );
+    }
+    let metric = PrefixFormat {
+        engineering: false,
+        precision: Some(1),
+        ..PrefixFormat::default()
+    };
+    let universe = RuntimeValue {
+        value: 8.8e26,
+        unit: METER,
+    };
+    println!("{} m -> {}", universe.value, universe.prefixed());
+    println!(
+        "{:?} -> {}, {:?} -> {:?}",
+        Quetta,
+        SIPrefix::from(Quetta).multiplier(),
+        SIPrefix::Kilo,
+        Kilo::try_from(SIPrefix::Kilo)
+    );
+    let height = RuntimeValue {
+        value: 0.25,
+        unit: METER,
+    };
+    println!(
+        "{} m -> {}",
+        height.value,
+        format_prefixed(&height, &metric)
+    );
+}
+
+fn catalog() {
+    use ferrunitas::gemini::units::{Ampere, Joule, Ohm, Second, Volt, Watt};
+    use ferrunitas::temp::parse::parse_value;
+
+    let current: Value<f64, Ampere> = Value::new(0.5);
+    let resistance: Value<f64, Ohm> = Value::new(220.0);
+    let voltage: Value<f64, Volt> = (current * resistance).convert();
+    let power: Value<f64, Watt> = (voltage * current).convert();
+    let energy: Value<f64, Joule> = (power * Value::<f64, Second>::new(60.0)).convert();
+    println!(
+        "{} · {} = {}, {}, {} per minute",
+        current, resistance, voltage, power, energy
+    );
+
+    for literal in ["3.3 kΩ", "100 nF", "2 mSv", "1 kat", "500 lx"] {
+        let value = parse_value(literal).unwrap();
+        println!("{} -> {} ({})", literal, value.prefixed(), value.unit.name);
+    }
+}
+
+fn information() {
+    use ferrunitas::gemini::units::{BitPerSecond, BytePerSecond, Mebibyte, Second};
+    use ferrunitas::temp::parse::parse_value;
+    use ferrunitas::temp::units::BYTE;
+    use ferrunitas::temp::value::Value as RuntimeValue;
+
+    for literal in ["1536 KiB", "4.7 GB", "3 Mibit", "64 o"] {
+        let value = parse_value(literal).unwrap();
+        println!(
+            "{} -> {} = {} bit",
+            literal,
+            value.prefixed(),
+            value.to_si()
+        );
+    }
+    let file = RuntimeValue {
+        value: 1_500_000.0,
+        unit: BYTE,
+    };
+    println!("{} B -> {}", file.value, file.prefixed());
+
+    let download: Value<f64, Mebibyte> = Value::new(700.0);
+    let rate: Value<f64, BitPerSecond> = (download / Value::<f64, Second>::new(56.0)).as_kind();
+    let rate_bytes: Value<f64, BytePerSecond> = rate.convert();
+    println!("{} in 56 s = {} = {}", download, rate, rate_bytes);
+}
+
+fn customary() {
+    use ferrunitas::gemini::units::{Gallon, Horsepower, ImperialGallon, Liter, Mile, Psi, Watt};
+    use ferrunitas::temp::parse::parse_value;
+    use ferrunitas::temp::units::{KILOGRAM, PASCAL};
+
+    let tank: Value<f64, Gallon> = Value::new(15.0);
+    let uk: Value<f64, ImperialGallon> = tank.convert();
+    let liters: Value<f64, Liter> = tank.convert();
+    println!("{} = {} = {}", tank, uk, liters);
+
+    let engine: Value<f64, Horsepower> = Value::new(300.0);
+    let kw: Value<f64, PrefixedUnit<Kilo, Watt>> = engine.convert();
+    let w: Value<f64, Watt> = engine.convert();
+    println!("{} = {} = {}", engine, kw, w);
+
+    let marathon: Value<f64, Mile> = Value::new(26.2188);
+    println!(
+        "{} = {}",
+        marathon,
+        marathon.convert::<ferrunitas::gemini::units::Meter>()
+    );
+    let tire: Value<f64, Psi> = Value::new(32.0);
+    println!(
+        "{} = {}",
+        tire,
+        tire.convert::<ferrunitas::gemini::units::Pascal>()
+    );
+
+    let weight = parse_value("11 st").unwrap();
+    println!("11 st = {} kg", weight.convert_to(KILOGRAM).unwrap().value);
+    let pressure = parse_value("14.7 psi").unwrap();
+    println!(
+        "14.7 psi = {} Pa",
+        pressure.convert_to(PASCAL).unwrap().value
+    );
+}
+
+fn constants() {
+    use ferrunitas::gemini::constants::{
+        AVOGADRO, BOLTZMANN, GRAVITATIONAL, PLANCK, SPEED_OF_LIGHT, STANDARD_GRAVITY,
+    };
+    use ferrunitas::gemini::units::{Joule, Kilogram, Newton, Pound, PoundForce};
+
+    let m: Value<f64, Kilogram> = Value::new(0.001);
+    let e: Value<f64, Joule> = (m * SPEED_OF_LIGHT.value * SPEED_OF_LIGHT.value).convert();
+    println!("E = m·c² = {}", e);
+
+    let weight: Value<f64, Newton> =
+        (Value::<f64, Pound>::new(1.0) * STANDARD_GRAVITY.value).convert();
+    println!(
+        "1 lb · g₀ = {} = {}",
+        weight,
+        weight.convert::<PoundForce>()
+    );
+
+    for c in [
+        SPEED_OF_LIGHT.to_string(),
+        PLANCK.to_string(),
+        BOLTZMANN.to_string(),
+        AVOGADRO.to_string(),
+        GRAVITATIONAL.to_string(),
+    ] {
+        println!("{}", c);
+    }
+    println!(
+        "G exact: {}, h exact: {}",
+        GRAVITATIONAL.is_exact(),
+        PLANCK.is_exact()
+    );
+}
+
+fn typed() {
+    use ferrunitas::dim::P2;
+    use ferrunitas::typed::quantity::quantity;
+    use ferrunitas::typed::unit::unit;
+    use ferrunitas::typed::value::Value as TypedValue;
+
+    type Length = quantity!([L 1, M 0, T 0, I 0, Th 0, N 0, J 0]);
+    type Time = quantity!([L 0, M 0, T 1, I 0, Th 0, N 0, J 0]);
+    type Velocity = quantity!([(Length, 1), (Time, -1)]);
+    unit!(METER, quantity = Length, scale = 1.0, symbol = "m");
+    unit!(KILOMETER, quantity = Length, scale = 1000.0, symbol = "km");
+    unit!(HOUR, quantity = Time, scale = 3600.0, symbol = "h");
+    unit!(
+        METER_PER_SECOND,
+        quantity = Velocity,
+        scale = 1.0,
+        symbol = "m/s"
+    );
+
+    // Derived units and values fall out of the operands' dimensions
+    let kilometer_per_hour = KILOMETER / HOUR;
+    let distance = TypedValue::new(42.195, KILOMETER);
+    let time = TypedValue::new(2.0, HOUR);
+    let speed = distance / time;
+    println!(
+        "{} km / {} h = {} km/h = {} m/s",
+        distance.get(KILOMETER),
+        time.get(HOUR),
+        speed.get(kilometer_per_hour),
+        speed.get(METER_PER_SECOND)
+    );
+    let area = distance.powi::<P2>();
+    println!("{:?} = {} km²", area, area.get(KILOMETER.powi::<P2>()));
+    println!("{:?}", kilometer_per_hour);
+}
+
+fn roots() {
+    use ferrunitas::temp::parse::{parse_unit, parse_value};
+    use ferrunitas::temp::quantity::Exponent;
+
+    for expr in [
+        "nV/√Hz",
+        "MPa·√m",
+        "Hz^-½",
+        "m^(1/2)",
+        "s^0.5",
+        "m⁻¹ᐟ²",
+        "∛(m³)",
+    ] {
+        let mut unit = parse_unit(expr).unwrap();
+        println!("{} -> {} x {}", expr, unit.repr(), unit.to_si(1.0));
+    }
+    let noise = parse_value("4 nV/√Hz").unwrap();
+    let bandwidth = parse_value("10 kHz").unwrap();
+    let mut rms = noise * bandwidth.sqrt();
+    println!(
+        "4 nV/√Hz over 10 kHz = {} V, {}",
+        rms.to_si(),
+        rms.unit.quantity.repr()
+    );
+    let fractal = parse_value("8 m")
+        .unwrap()
+        .powf(Exponent::new(3, 2).unwrap());
+    println!(
+        "(8 m)^3/2 = {} {:?}",
+        fractal.value, fractal.unit.quantity.dimension
+    );
+
+    use ferrunitas::typed::quantity::quantity;
+    use ferrunitas::typed::unit::unit;
+    use ferrunitas::typed::value::Value as TypedValue;
+    type Area = quantity!([L 2, M 0, T 0, I 0, Th 0, N 0, J 0]);
+    unit!(HECTARE, quantity = Area, scale = 10_000.0, symbol = "ha");
+    let field = TypedValue::new(1.0, HECTARE);
+    println!("side of 1 ha = {:?}", field.sqrt());
+}
+
+fn extra_dimensions() {
+    use ferrunitas::dim::{
+        name_extra_dimensions, ExtraDimensions, ExtraSymbols, FIRST_EXTRA_DIMENSION,
+    };
+    use ferrunitas::gemini::units::{Hour, Second, Watt};
+    use ferrunitas::temp::parse::parse_value_with;
+    use ferrunitas::temp::parse::NumberFormat;
+    use ferrunitas::temp::prefixes::{Prefix, PrefixSystem};
+    use ferrunitas::temp::quantity::{base_dimension, Quantity as RuntimeQuantity};
+    use ferrunitas::temp::unit::{Components, Unit as RuntimeUnit};
+    use ferrunitas::temp::units::UNITS;
+
+    // Compile time: money and requests are base dimensions of their own
+    quantity!(Money, dim: X0, Euro, "€");
+    quantity!(Requests, dim: X1, Request, "req");
+    quantity!(
+        RequestRate,
+        [(Requests, 1), (Time, -1)],
+        RequestPerSecond,
+        "req/s"
+    );
+    type KilowattHour = ferrunitas::gemini::UnitMul<PrefixedUnit<Kilo, Watt>, Hour>;
+    type Time = <Second as Unit>::Quantity;
+
+    let tariff = Value::<f64, Euro>::new(0.32) / Value::<f64, KilowattHour>::new(1.0);
+    let consumption: Value<f64, KilowattHour> = Value::new(3500.0);
+    let bill: Value<f64, Euro> = (tariff * consumption).convert();
+    println!("3500 kWh at 0.32 €/kWh = {}", bill);
+
+    let served = Value::<f64, Request>::new(1.2e6);
+    let rate: Value<f64, RequestPerSecond> = (served / Value::<f64, Hour>::new(1.0)).convert();
+    println!("{} per hour = {}", served, rate);
+
+    // Runtime: a request unit on the next free lane, added to the catalog for parsing
+    let requests =
+        RuntimeQuantity::new(base_dimension(FIRST_EXTRA_DIMENSION + 1), Some("requests"));
+    let request = RuntimeUnit {
+        quantity: requests,
+        factor: 1.0,
+        offset: 0.0,
+        prefix: Prefix::NONE,
+        name: "request",
+        symbol: "req",
+        prefix_system: PrefixSystem::SI,
+        components: Components::NONE,
+    };
+    let units: Vec<RuntimeUnit> = UNITS.iter().copied().chain([request]).collect();
+    let load = parse_value_with("12 kreq/min", &NumberFormat::default(), &units).unwrap();
+    // Without a name the lane prints as X1 (dimension) or x1 (base unit)
+    struct Lanes;
+    impl ExtraDimensions for Lanes {
+        const SYMBOLS: ExtraSymbols = [Some(("C", "€")), Some(("R", "req")), None];
+    }
+    name_extra_dimensions::<Lanes>();
+    println!(
+        "12 kreq/min = {} req/s [{}]",
+        load.to_si(),
+        load.unit.quantity.dimension_display()
+    );
+}
+
+fn angles() {
+    use ferrunitas::gemini::units::{
+        Degree, Foot, Hertz, Meter, Radian, RadianPerSecond, RevolutionPerMinute, Turn,
+    };
+    use ferrunitas::temp::parse::parse_value;
+
+    let slope: Value<f64, Degree> = Value::new(30.0);
+    println!(
+        "sin {} = {:.3}, cos {} = {:.3}",
+        slope,
+        slope.sin(),
+        slope,
+        slope.cos()
+    );
+    let rise: Value<f64, Foot> = Value::new(10.0);
+    let run: Value<f64, Meter> = Value::new(100.0);
+    let grade = rise.atan2(run).convert::<Degree>();
+    println!("{} over {} = {:.3}°", rise, run, grade.value);
+    println!(
+        "asin 0.5 = {:.4}°",
+        Value::<f64, Radian>::asin(0.5).convert::<Degree>().value
+    );
+
+    let spindle: Value<f64, RevolutionPerMinute> = Value::new(3000.0);
+    let omega: Value<f64, RadianPerSecond> = spindle.convert();
+    println!("{} = {:.2} rad/s", spindle, omega.value);
+    println!("{}", Value::<f64, Turn>::new(0.25).convert::<Degree>());
+    // Parsing keeps the kind: rpm is an angular velocity, not a frequency
+    let parsed: Result<Value<f64, RadianPerSecond>, _> = "3000 rpm".parse();
+    let mistaken: Result<Value<f64, Hertz>, _> = "3000 rpm".parse();
+    println!(
+        "3000 rpm -> {:.2} / Hz: {}",
+        parsed.unwrap(),
+        mistaken.unwrap_err()
+    );
+
+    for literal in ["45°", "30′", "1.5 mrad", "200 gon", "0.5 sr", "2 tr"] {
+        match parse_value(literal) {
+            Ok(value) => println!(
+                "{} -> {} {:?}",
+                literal,
+                value.to_si(),
+                value.unit.quantity.dimension
+            ),
+            Err(e) => println!("{} -> {}", literal, e),
+        }
+    }
+
+    use ferrunitas::dim::base::A;
+    use ferrunitas::typed::value::Value as TypedValue;
+    let heading = TypedValue::<A>::atan(1.0);
+    println!(
+        "typed atan 1 = {} rad, sin = {:.4}",
+        heading.base,
+        heading.sin()
+    );
+}
+
+fn kinds() {
+    use ferrunitas::gemini::units::{Becquerel, Hertz, Joule, Meter, Newton, NewtonMeter, Second};
+    use ferrunitas::gemini::One;
+    use ferrunitas::temp::parse::parse_value;
+    use ferrunitas::temp::units::{HERTZ, JOULE, NEWTON_METER};
+
+    // N·m comes out generic: it converts to joules, and becomes a torque only when asked to
+    let lever = Value::<f64, Newton>::new(40.0) * Value::<f64, Meter>::new(0.25);
+    let work: Value<f64, Joule> = lever.convert();
+    let torque: Value<f64, NewtonMeter> = lever.as_kind();
+    println!("40 N × 0.25 m = {} of work or {} of torque", work, torque);
+    // torque.convert::<Joule>() does not compile, Joule and NewtonMeter are different kinds
+
+    let counts = Value::<f64, One>::new(1200.0) / Value::<f64, Second>::new(60.0);
+    let activity: Value<f64, Becquerel> = counts.as_kind();
+    let rate: Value<f64, Hertz> = activity.as_kind();
+    println!("{} -> {} -> {}", counts, activity, rate);
+    // activity.as_kind::<RadianPerSecond>() does not compile either, one kind never becomes another
+
+    let decay = parse_value("3 kBq").unwrap();
+    println!(
+        "3 kBq in Hz: {:?}, as 1/s: {:?}",
+        decay.convert_to(HERTZ).map(|v| v.value),
+        decay.as_kind(HERTZ).map(|v| v.value)
+    );
+    let moment = parse_value("12 N·m").unwrap();
+    println!(
+        "12 N·m in J: {:?}, as torque: {:?}",
+        moment.convert_to(JOULE).map(|v| v.value),
+        moment.as_kind(NEWTON_METER).map(|v| v.value)
+    );
+
+    use ferrunitas::dim::Kind;
+    use ferrunitas::typed::quantity::quantity;
+    use ferrunitas::typed::unit::unit;
+    use ferrunitas::typed::value::Value as TypedValue;
+    struct TorqueKind;
+    impl Kind for TorqueKind {
+        const NAME: &'static str = "torque";
+    }
+    type Energy = quantity!([L 2, M 1, T -2, I 0, Th 0, N 0, J 0]);
+    type Torque = quantity!(kind: TorqueKind, [(Energy, 1)]);
+    unit!(JOULE_T, quantity = Energy, scale = 1.0, symbol = "J");
+    unit!(
+        NEWTON_METER_T,
+        quantity = Torque,
+        scale = 1.0,
+        symbol = "N·m"
+    );
+    let wrench = TypedValue::new(35.0, NEWTON_METER_T);
+    let energy = TypedValue::new(35.0, JOULE_T);
+    println!(
+        "typed: {} N·m as generic = {} J, energy {:?}",
+        wrench.get(NEWTON_METER_T),
+        wrench.as_kind().get(JOULE_T),
+        energy
+    );
+}
+
+fn names() {
+    use ferrunitas::dim::{Kind, P2};
+    use ferrunitas::typed::quantity::{quantity, reg};
+    use ferrunitas::typed::unit::unit;
+    use ferrunitas::typed::value::Value as TypedValue;
+
+    struct TorqueKind;
+    impl Kind for TorqueKind {
+        const NAME: &'static str = "torque";
+    }
+    type Length = quantity!([L 1, M 0, T 0, I 0, Th 0, N 0, J 0]);
+    type Force = quantity!([L 1, M 1, T -2, I 0, Th 0, N 0, J 0]);
+    type Torque = quantity!(kind: TorqueKind, [(Force, 1), (Length, 1)]);
+    unit!(NEWTON, quantity = Force, scale = 1.0, symbol = "N");
+    unit!(METER, quantity = Length, scale = 1.0, symbol = "m");
+    unit!(NEWTON_METER, quantity = Torque, scale = 1.0, symbol = "N·m");
+
+    let force = TypedValue::new(9.81, NEWTON);
+    println!("{} is a {}, {:?}", force, Force::default(), force);
+    let work = force * TypedValue::new(2.0, METER);
+    println!("{} of {}", work, work.quantity());
+
+    let torque = TypedValue::new(12.0, NEWTON_METER);
+    println!("before registering: {}", torque);
+    reg::register::<Torque>("torque", "N·m");
+    println!("after registering: {}, {:?}", torque, torque);
+    println!("unnamed: {}", force.powi::<P2>());
+}
+
+fn dimensions() {
+    use ferrunitas::dim::{DimensionDisplay, Notation, Symbols};
+    use ferrunitas::gemini::units::{MolePerCubicMeter, PoundForce};
+    use ferrunitas::temp::parse::parse_unit;
+
+    let force = Value::<f64, PoundForce>::new(1.0).dimension();
+    for notation in [Notation::Unicode, Notation::Ascii, Notation::Latex] {
+        println!(
+            "{:?}: {} = {}",
+            notation,
+            force.symbols(Symbols::Dimensions).notation(notation),
+            force.notation(notation)
+        );
+    }
+    let concentration = Value::<f64, MolePerCubicMeter>::new(1.0).dimension();
+    println!("{}", concentration.notation(Notation::Ascii));
+
+    for expr in ["V/√Hz", "Ω·m", "1/s"] {
+        let mut unit = parse_unit(expr).unwrap();
+        println!(
+            "{} -> {}, {}",
+            expr,
+            unit.quantity.repr(),
+            unit.quantity.dimension_display().notation(Notation::Ascii)
+        );
+    }
+
+    use ferrunitas::typed::quantity::{enc, quantity, EncodedDimensions};
+    type Jerk = quantity!([L 1, M 0, T -3, I 0, Th 0, N 0, J 0]);
+    println!(
+        "jerk: {} / {}",
+        Jerk::default(),
+        enc::display(<Jerk as EncodedDimensions>::EDV).notation(Notation::Latex)
+    );
+    println!(
+        "{}",
+        DimensionDisplay::from_integers([0; ferrunitas::dim::DIMENSIONS])
+    );
+}
+
+fn simplification() {
+    use ferrunitas::temp::parse::{parse_unit, parse_value};
+    use ferrunitas::temp::units::NEWTON_METER;
+
+    let power = parse_value("230 V").unwrap() * parse_value("4 A").unwrap();
+    println!("230 V · 4 A = {} ({})", power.prefixed(), power.unit.name);
+    let work = parse_value("2000 N").unwrap() * parse_value("3 m").unwrap();
+    println!(
+        "2000 N · 3 m = {} {}{}",
+        work.value,
+        work.unit.prefix.abbreviation(),
+        work.unit.symbol
+    );
+    let torque = work.as_kind(NEWTON_METER).unwrap();
+    println!("as a torque: {} {}", torque.value, torque.unit.symbol);
+
+    for expr in ["kg·m²·s⁻³", "A·s", "1/Ω", "J/s", "mol/s"] {
+        let unit = parse_unit(expr).unwrap().simplify();
+        println!("{} -> {} ({})", expr, unit.symbol, unit.name);
+    }
+}
+
+fn composites() {
+    use ferrunitas::temp::parse::parse_value;
+    use ferrunitas::temp::units::{AMPERE, HOUR, METER, SECOND};
+
+    let km = parse_value("1 km").unwrap().unit;
+    let ms = parse_value("1 ms").unwrap().unit;
+    for unit in [
+        km * ms,
+        km / ms,
+        (km / ms) / ms,
+        METER * METER / SECOND,
+        km / HOUR,
+    ] {
+        println!("{} = {} {}", unit, unit.to_si(1.0), unit.quantity);
+    }
+
+    let work = parse_value("2 kN").unwrap() * parse_value("3 m").unwrap();
+    println!("2 kN · 3 m = {}", work.prefixed());
+    let charge = parse_value("2.5 mA").unwrap().unit * HOUR;
+    println!("mA·h = {} C, {}", charge.to_si(1.0), charge / AMPERE);
+}
+
+fn chained() {
+    use ferrunitas::gemini::units::{Foot, Gram, Inch, Mile, Ounce, Pound};
+    use ferrunitas::gemini::ConvertibleToQuantityBaseUnit;
+
+    // Units defined relative to other units; the factors are composed at compile time
+    unit!(Furlong, 660.0 * Foot, "fur");
+    unit!(League, 3.0 * Mile, "lea");
+    type Kilofoot = PrefixedUnit<Kilo, Foot>;
+
+    println!(
+        "1 in = {} m",
+        <Inch as ConvertibleToQuantityBaseUnit>::FACTOR_TO_QUANTITY_BASE
+    );
+    println!(
+        "1 mi = {} m",
+        <Mile as ConvertibleToQuantityBaseUnit>::FACTOR_TO_QUANTITY_BASE
+    );
+    println!(
+        "Furlong measures {}",
+        std::any::type_name::<<Furlong as Unit>::Quantity>()
+    );
+
+    let race: Value<f64, Furlong> = Value::new(8.0);
+    println!("{} = {}", race, race.convert::<Mile>());
+    let lea: Value<f64, League> = Value::new(1.0);
+    println!("{} = {}", lea, lea.convert::<Kilofoot>());
+
+    // The raw gram is its own base, so it converts like any other unit
+    let parcel: Value<f64, Ounce> = Value::new(16.0);
+    println!(
+        "{} = {} = {}",
+        parcel,
+        parcel.convert::<Pound>(),
+        parcel.convert::<Gram>()
+    );
+    print_unit_details::<Gram>();
+}
+
 fn main() {
+    mass();
     time();
+    force();
+    temperature();
+    levels();
+    parsing();
+    prefixes();
+    catalog();
+    information();
+    customary();
+    constants();
+    typed();
+    roots();
+    extra_dimensions();
+    angles();
+    kinds();
+    names();
+    dimensions();
+    simplification();
+    composites();
+    chained();
 }
@@ -0,0 +1,8 @@
+pub mod format;
+pub mod parse;
+pub mod prefixes;
+pub mod quantity;
+pub mod quantity_trait;
+pub mod unit;
+pub mod units;
+pub mod value;
@@ -0,0 +1,147 @@
+use crate::temp::prefixes::{BinaryPrefix, Prefix, PrefixSystem, SIPrefix};
+use crate::temp::value::Value;
+
+// How a value picks its prefix for display
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrefixFormat {
+    pub engineering: bool,    // only powers of 1000 (k, M, m, μ, ...), no h/da/d/c
+    pub binary: Option<bool>, // None: binary prefixes for values in binary units (KiB, or plain B)
+    pub precision: Option<usize>, // digits after the decimal point
+}
+
+impl Default for PrefixFormat {
+    fn default() -> Self {
+        PrefixFormat {
+            engineering: true,
+            binary: None,
+            precision: None,
+        }
+    }
+}
+
+// Rounds to 12 significant digits so 0.1 + 0.2 shows as 0.3
+fn trim_noise(value: f64) -> f64 {
+    if value == 0.0 || !value.is_finite() {
+        return value;
+    }
+    let magnitude = value.abs().log10().floor() as i32;
+    let scale = 10f64.powi(11 - magnitude);
+    (value * scale).round() / scale
+}
+
+fn mantissa(value: f64, format: &PrefixFormat) -> String {
+    match format.precision {
+        Some(precision) => format!("{:.*}", precision, value),
+        None => format!("{}", trim_noise(value)),
+    }
+}
+
+fn si_prefix(raw: f64, engineering: bool) -> SIPrefix {
+    let allowed = SIPrefix::ALL
+        .iter()
+        .copied()
+        .filter(|p| !engineering || p.multiplier().log10().round() as i32 % 3 == 0);
+    let mut smallest = SIPrefix::None;
+    for prefix in allowed {
+        // Compare with a little slack so 999.9999999999 mm still becomes 1 m
+        if raw.abs() >= prefix.multiplier() * (1.0 - 1e-12) {
+            return prefix;
+        }
+        smallest = prefix;
+    }
+    smallest
+}
+
+fn binary_prefix(raw: f64) -> BinaryPrefix {
+    BinaryPrefix::ALL
+        .iter()
+        .copied()
+        .find(|p| raw.abs() >= p.multiplier())
+        .unwrap_or(BinaryPrefix::None)
+}
+
+// Formats a value with the prefix that keeps the mantissa in [1, 1000), e.g. 0.0012 m -> "1.2 mm".
+// Units that take no prefixes (hour, degree Celsius) and composite units (km·h⁻¹) are printed as
+// they are.
+pub fn format_prefixed(value: &Value, format: &PrefixFormat) -> String {
+    let unit = value.unit;
+    if unit.prefix_system == PrefixSystem::None {
+        return format!("{} {}", mantissa(value.value, format), unit);
+    }
+
+    let raw = value.value * unit.prefix.multiplier();
+    if raw == 0.0 || !raw.is_finite() {
+        return format!("{} {}", mantissa(raw, format), unit.symbol);
+    }
+
+    // An explicit SI prefix (1.5 kB) keeps the value decimal
+    let binary = format.binary.unwrap_or(match unit.prefix {
+        Prefix::Binary(_) => true,
+        Prefix::SI(prefix) => {
+            unit.prefix_system == PrefixSystem::Binary && prefix == SIPrefix::None
+        }
+    });
+    let (multiplier, abbreviation) = if binary {
+        let prefix = binary_prefix(raw);
+        (prefix.multiplier(), prefix.abbreviation())
+    } else {
+        let prefix = si_prefix(raw, format.engineering);
+        (prefix.multiplier(), prefix.abbreviation())
+    };
+    format!(
+        "{} {}{}",
+        mantissa(raw / multiplier, format),
+        abbreviation,
+        unit.symbol
+    )
+}
+
+impl Value {
+    pub fn prefixed(&self) -> String {
+        format_prefixed(self, &PrefixFormat::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp::unit::Unit;
+    use crate::temp::units::{BYTE, HOUR, METER, SECOND};
+
+    fn value(value: f64, unit: Unit) -> Value {
+        Value { value, unit }
+    }
+
+    #[test]
+    fn trim_noise_rounds_to_twelve_significant_digits() {
+        assert_eq!(trim_noise(0.1 + 0.2), 0.3);
+        assert_eq!(trim_noise(1.0 - 1e-15), 1.0);
+        assert_eq!(trim_noise(123_456.789), 123_456.789);
+        assert_eq!(trim_noise(-0.7 - 0.1), -0.8);
+        assert_eq!(trim_noise(3.0 * 1.1e-9), 3.3e-9);
+        assert_eq!(trim_noise(0.0), 0.0);
+        assert!(trim_noise(f64::NAN).is_nan());
+        assert_eq!(trim_noise(f64::INFINITY), f64::INFINITY);
+    }
+
+    #[test]
+    fn prefixes_keep_the_mantissa_in_range() {
+        assert_eq!(value(0.0012, METER).prefixed(), "1.2 mm");
+        assert_eq!(value(1500.0, METER).prefixed(), "1.5 km");
+        assert_eq!(value(0.1 + 0.2, METER).prefixed(), "300 mm");
+        assert_eq!(value(999.999_999_999_9e-3, METER).prefixed(), "1 m");
+        assert_eq!(value(0.0, METER).prefixed(), "0 m");
+        assert_eq!(value(2.5, HOUR).prefixed(), "2.5 h");
+        assert_eq!(value(1536.0, BYTE).prefixed(), "1.5 KiB");
+
+        let format = PrefixFormat {
+            engineering: false,
+            binary: Some(false),
+            precision: Some(2),
+        };
+        assert_eq!(format_prefixed(&value(0.05, METER), &format), "5.00 cm");
+        assert_eq!(format_prefixed(&value(1536.0, BYTE), &format), "1.54 kB");
+        let speed = value(3.0, METER) / value(1.0, SECOND);
+        assert_eq!(speed.prefixed(), "3 m·s⁻¹");
+    }
+}
@@ -0,0 +1,1102 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::dim::{Dimension, KindName, DERIVED_KIND, DIMENSIONS};
+use crate::gemini::{self, ConvertibleToQuantityBaseUnit};
+use crate::temp::prefixes::{BinaryPrefix, Prefix, PrefixSystem, SIPrefix};
+use crate::temp::quantity::{dimension_vector, DimensionVector, Exponent, Quantity};
+use crate::temp::unit::{Components, Unit};
+use crate::temp::units::{LOG_UNITS, ONE, UNITS};
+use crate::temp::value::Value;
+
+// Parser for unit expressions such as "kg·m/s^2", "km/h", "W m-2" or "J/(mol·K)".
+//   expr   := term ((* | · | / | <space>) term)*   (left-associative, no operator means multiply)
+//   term   := factor exponent?                     (^-2, **2, -2, ², ⁻¹, ^(1/2), ^0.5, ½, ⁻¹ᐟ²)
+//   factor := symbol | 1 | ( expr ) | √ factor | ∛ factor
+// Symbols are looked up in a unit table, optionally after stripping an SI prefix (km, µs, daN)
+// or, for information units, a binary prefix (KiB, Mibit).
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    UnknownUnit {
+        token: String,
+        position: usize,
+    },
+    UnexpectedToken {
+        token: String,
+        position: usize,
+    },
+    UnexpectedEnd {
+        position: usize,
+    },
+    // Not a number, or a malformed or non-finite one; reason says which
+    InvalidNumber {
+        token: String,
+        position: usize,
+        reason: &'static str,
+    },
+    // A logarithmic unit (dB, Np, pH) where a factor of a unit expression was expected
+    LogarithmicUnit {
+        token: String,
+        position: usize,
+    },
+    // An exponent, or a product of exponents, that does not fit an i32 ratio
+    ExponentOutOfRange {
+        position: usize,
+    },
+    // Boxed, two dimension vectors would make every Result large
+    DimensionMismatch {
+        expected: Box<DimensionVector>,
+        found: Box<DimensionVector>,
+    },
+    // Right dimension, wrong kind (rpm for Hz, J for N·m); None is a generic quantity
+    KindMismatch {
+        expected: Option<&'static str>,
+        found: Option<&'static str>,
+    },
+}
+
+impl ParseError {
+    // Moves the reported position, for errors in a sub-expression.
+    fn shifted(self, by: usize) -> Self {
+        match self {
+            ParseError::UnknownUnit { token, position } => ParseError::UnknownUnit {
+                token,
+                position: position + by,
+            },
+            ParseError::UnexpectedToken { token, position } => ParseError::UnexpectedToken {
+                token,
+                position: position + by,
+            },
+            ParseError::UnexpectedEnd { position } => ParseError::UnexpectedEnd {
+                position: position + by,
+            },
+            ParseError::InvalidNumber {
+                token,
+                position,
+                reason,
+            } => ParseError::InvalidNumber {
+                token,
+                position: position + by,
+                reason,
+            },
+            ParseError::LogarithmicUnit { token, position } => ParseError::LogarithmicUnit {
+                token,
+                position: position + by,
+            },
+            ParseError::ExponentOutOfRange { position } => ParseError::ExponentOutOfRange {
+                position: position + by,
+            },
+            error @ (ParseError::DimensionMismatch { .. } | ParseError::KindMismatch { .. }) => {
+                error
+            }
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnknownUnit { token, position } => {
+                write!(f, "unknown unit '{}' at position {}", token, position)
+            }
+            ParseError::UnexpectedToken { token, position } => {
+                write!(f, "unexpected '{}' at position {}", token, position)
+            }
+            ParseError::UnexpectedEnd { position } => {
+                write!(f, "unexpected end of expression at position {}", position)
+            }
+            ParseError::InvalidNumber {
+                token,
+                position,
+                reason,
+            } => write!(
+                f,
+                "invalid number '{}' at position {}: {}",
+                token, position, reason
+            ),
+            ParseError::LogarithmicUnit { token, position } => write!(
+                f,
+                "logarithmic unit '{}' at position {} is not a factor of a unit expression",
+                token, position
+            ),
+            ParseError::ExponentOutOfRange { position } => {
+                write!(f, "exponent out of range at position {}", position)
+            }
+            ParseError::DimensionMismatch { expected, found } => write!(
+                f,
+                "expected {}, found {}",
+                describe(expected),
+                describe(found)
+            ),
+            ParseError::KindMismatch { expected, found } => write!(
+                f,
+                "expected {}, found {}",
+                expected.unwrap_or("a generic quantity"),
+                found.unwrap_or("a generic quantity")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// A dimension in SI base units, after its name: registered, or that of a generic built-in
+// quantity, e.g. "velocity [m·s⁻¹]"
+fn describe(dimension: &DimensionVector) -> String {
+    let mut quantity = Quantity::new(*dimension, None);
+    quantity.name = quantity.name().or_else(|| {
+        UNITS
+            .iter()
+            .map(|u| u.quantity)
+            .find(|q| q.dimension == *dimension && q.kind.is_none())
+            .and_then(|q| q.name)
+    });
+    quantity.repr()
+}
+
+// Parses a unit expression against the built-in unit table.
+pub fn parse_unit(expr: &str) -> Result<Unit, ParseError> {
+    parse_unit_with(expr, UNITS)
+}
+
+// Parses a unit expression against a custom unit table.
+pub fn parse_unit_with(expr: &str, units: &[Unit]) -> Result<Unit, ParseError> {
+    let tokens = tokenize(expr)?;
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+        end: expr.chars().count(),
+        units,
+    };
+    let components = parser.expr()?;
+    if let Some((token, position)) = parser.tokens.get(parser.pos) {
+        return Err(ParseError::UnexpectedToken {
+            token: token.to_string(),
+            position: *position,
+        });
+    }
+    // Each exponent fits, but their sums over a dimension may not: m^2000000000·m^2000000000
+    combine(&components).ok_or(ParseError::ExponentOutOfRange { position: 0 })
+}
+
+impl FromStr for Unit {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_unit(s)
+    }
+}
+
+// ---------- values ----------
+// Number syntax of quantity literals. By default both "1.5" and "1,5" are accepted;
+// set a group separator to read "1,000.5" style numbers.
+#[derive(Debug, Clone, Copy)]
+pub struct NumberFormat {
+    pub decimal_separators: &'static [char],
+    pub group_separator: Option<char>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        NumberFormat {
+            decimal_separators: &['.', ','],
+            group_separator: None,
+        }
+    }
+}
+
+// Parses a quantity literal such as "5.3 km/h", "-12e3 mA" or "1,5 kPa".
+pub fn parse_value(literal: &str) -> Result<Value, ParseError> {
+    parse_value_with(literal, &NumberFormat::default(), UNITS)
+}
+
+pub fn parse_value_with(
+    literal: &str,
+    format: &NumberFormat,
+    units: &[Unit],
+) -> Result<Value, ParseError> {
+    let chars: Vec<char> = literal.chars().collect();
+    let start = chars.iter().take_while(|c| c.is_whitespace()).count();
+    let (value, end) = number(&chars, start, format)?;
+    let expr: String = chars[end..].iter().collect();
+    let unit = if expr.trim().is_empty() {
+        ONE
+    } else {
+        parse_unit_with(&expr, units).map_err(|e| e.shifted(end))?
+    };
+    Ok(Value { value, unit })
+}
+
+// Parses a quantity literal into a typed value, checking its dimension and kind against U.
+// A named unit keeps its kind, so "60 rpm" is no Hertz and "12 J" no NewtonMeter; a product
+// written out ("N·m", "rad/s", "cd·sr") takes U's, as with Value::as_kind, unless one of its
+// factors is of U's dimension with another kind and the others have none: "5 Bq·1" is no Hertz
+// and "5 J·1" no torque.
+// The gemini quantity base is assumed to be the coherent SI unit, as for all SI-based quantity! definitions.
+pub fn parse_typed<U>(literal: &str) -> Result<gemini::Value<f64, U>, ParseError>
+where
+    U: gemini::Unit,
+    U::Base: ConvertibleToQuantityBaseUnit,
+{
+    let parsed = parse_value(literal)?;
+    let expected =
+        dimension_vector(&<<U::Quantity as gemini::Quantity>::Dimension as Dimension>::EXPONENTS);
+    if parsed.unit.quantity.dimension != expected {
+        return Err(ParseError::DimensionMismatch {
+            expected: Box::new(expected),
+            found: Box::new(parsed.unit.quantity.dimension),
+        });
+    }
+    let expected = <<U::Quantity as gemini::Quantity>::Kind as KindName>::NAME;
+    let found = parsed.unit.quantity.kind;
+    let components = parsed.unit.components;
+    // An anonymous product has lost its factors, it can only be trusted when generic
+    let written_out = !components.is_empty() || (components.is_anonymous() && found.is_none());
+    let conflicting = components.iter().find(|c| {
+        c.exponent == Exponent::integer(1)
+            && c.quantity.dimension == parsed.unit.quantity.dimension
+            && c.quantity.kind != expected
+            && components
+                .iter()
+                .all(|other| other == *c || other.quantity.kind.is_none())
+    });
+    if found != expected && (!written_out || conflicting.is_some()) {
+        return Err(ParseError::KindMismatch {
+            expected,
+            found: conflicting.map_or(found, |c| c.quantity.kind),
+        });
+    }
+    let value_in_raw_base = (parsed.to_si()
+        - <U::Base as ConvertibleToQuantityBaseUnit>::OFFSET_TO_QUANTITY_BASE)
+        / <U::Base as ConvertibleToQuantityBaseUnit>::FACTOR_TO_QUANTITY_BASE;
+    Ok(gemini::Value::new(
+        value_in_raw_base / U::FACTOR_TO_UNIT_BASE,
+    ))
+}
+
+impl FromStr for Value {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_value(s)
+    }
+}
+
+impl<U> FromStr for gemini::Value<f64, U>
+where
+    U: gemini::Unit,
+    U::Base: ConvertibleToQuantityBaseUnit,
+{
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_typed(s)
+    }
+}
+
+// Reads a number starting at `i`: sign, digits with optional group separators,
+// optional fraction and optional exponent (e3, E-6). Returns the value and the index after it.
+fn number(chars: &[char], mut i: usize, format: &NumberFormat) -> Result<(f64, usize), ParseError> {
+    let start = i;
+    let mut normalized = String::new();
+    if let Some(c @ ('-' | '+' | '−')) = chars.get(i) {
+        normalized.push(if *c == '+' { '+' } else { '-' });
+        i += 1;
+    }
+    let mut seen_decimal = false;
+    let is_decimal = |c: Option<&char>| c.is_some_and(|c| format.decimal_separators.contains(c));
+    while let Some(&c) = chars.get(i) {
+        let followed_by_digit = chars.get(i + 1).is_some_and(|d| d.is_ascii_digit());
+        if c.is_ascii_digit() {
+            normalized.push(c);
+        } else if is_decimal(Some(&c))
+            && ((seen_decimal && followed_by_digit) || is_decimal(chars.get(i + 1)))
+        {
+            // "1,000.5" without a group separator, or "1..5": not a unit starting with "."
+            let end = i + chars[i..]
+                .iter()
+                .take_while(|c| c.is_ascii_digit() || is_decimal(Some(c)))
+                .count();
+            return Err(ParseError::InvalidNumber {
+                token: chars[start..end].iter().collect(),
+                position: start,
+                reason: "multiple decimal separators",
+            });
+        } else if is_decimal(Some(&c)) && !seen_decimal && followed_by_digit {
+            normalized.push('.');
+            seen_decimal = true;
+        } else if format.group_separator == Some(c) && !seen_decimal && followed_by_digit {
+            // Group separators are dropped
+        } else {
+            break;
+        }
+        i += 1;
+    }
+    // Exponent only if digits follow, so "5 Em" still reads as exametre
+    if let Some('e' | 'E') = chars.get(i) {
+        let mut j = i + 1;
+        if let Some('-' | '+') = chars.get(j) {
+            j += 1;
+        }
+        if chars.get(j).is_some_and(|d| d.is_ascii_digit()) {
+            while chars.get(j).is_some_and(|d| d.is_ascii_digit()) {
+                j += 1;
+            }
+            normalized.extend(&chars[i..j]);
+            i = j;
+        }
+    }
+    let token = || -> String {
+        chars[start..i.max(start + 1).min(chars.len())]
+            .iter()
+            .collect()
+    };
+    match normalized.parse::<f64>() {
+        Ok(value) if value.is_finite() => Ok((value, i)),
+        // 1e999 parses as infinity
+        Ok(_) => Err(ParseError::InvalidNumber {
+            token: token(),
+            position: start,
+            reason: "out of range",
+        }),
+        Err(_) if token().is_empty() => Err(ParseError::UnexpectedEnd { position: start }),
+        Err(_) => Err(ParseError::InvalidNumber {
+            token: token(),
+            position: start,
+            reason: "not a number",
+        }),
+    }
+}
+
+// A single unit keeps its identity (name, prefix, offset), anything else becomes a product that
+// remembers its factors (km·h⁻¹). None if a dimension exponent leaves the i32 range.
+fn combine(components: &[(Unit, Exponent)]) -> Option<Unit> {
+    if let [(unit, exp)] = components {
+        if *exp == Exponent::integer(1) {
+            return Some(*unit);
+        }
+    }
+    let mut dimension = [Exponent::ZERO; DIMENSIONS];
+    let mut factor = 1.0;
+    for (unit, exp) in components {
+        for (d, u) in dimension.iter_mut().zip(unit.quantity.dimension) {
+            *d = d.checked_add(u.checked_mul(*exp)?)?;
+        }
+        factor *= unit.scale().powf(exp.to_f64());
+    }
+    // As with Unit's Mul, a product of units with a kind is derived (see Quantity::product_kind)
+    let kind = components
+        .iter()
+        .any(|(unit, _)| unit.quantity.kind.is_some())
+        .then_some(DERIVED_KIND);
+    Some(Unit {
+        quantity: Quantity {
+            dimension,
+            name: None,
+            kind,
+        },
+        factor,
+        offset: 0.0,
+        prefix: Prefix::NONE,
+        name: "parsed_unit",
+        symbol: "",
+        prefix_system: PrefixSystem::None,
+        components: Components::of(components),
+    })
+}
+
+// Looks up a symbol, either verbatim or as a prefix followed by a known symbol.
+fn resolve(symbol: &str, units: &[Unit]) -> Option<Unit> {
+    if let Some(unit) = units.iter().find(|u| u.symbol == symbol) {
+        return Some(*unit);
+    }
+    let mut prefixes: Vec<(Prefix, &str)> = SIPrefix::ALL
+        .iter()
+        .filter(|p| **p != SIPrefix::None)
+        .map(|p| (Prefix::SI(*p), p.abbreviation()))
+        .chain([
+            (Prefix::SI(SIPrefix::Micro), "µ"),
+            (Prefix::SI(SIPrefix::Micro), "u"),
+        ])
+        .chain(
+            BinaryPrefix::ALL
+                .iter()
+                .filter(|p| **p != BinaryPrefix::None)
+                .map(|p| (Prefix::Binary(*p), p.abbreviation())),
+        )
+        .collect();
+    prefixes.sort_by_key(|(_, abbreviation)| std::cmp::Reverse(abbreviation.len()));
+    prefixes.into_iter().find_map(|(prefix, abbreviation)| {
+        let rest = symbol.strip_prefix(abbreviation)?;
+        units
+            .iter()
+            .find(|u| {
+                u.symbol == rest
+                    && u.prefix == Prefix::NONE
+                    && match (prefix, u.prefix_system) {
+                        (Prefix::SI(_), PrefixSystem::None) => false,
+                        // Binary units take the SI prefixes from kilo up as well: 1 kB = 1000 B,
+                        // but there is no decibyte or millibit
+                        (Prefix::SI(p), PrefixSystem::Binary) => p.multiplier() >= 1e3,
+                        (Prefix::SI(_), _) => true,
+                        (Prefix::Binary(_), system) => system == PrefixSystem::Binary,
+                    }
+            })
+            .map(|u| Unit { prefix, ..*u })
+    })
+}
+
+// ---------- tokens ----------
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Symbol(String),
+    Number(String),
+    Exponent(Exponent),
+    Root(i32), // √ or ∛
+    Mul,
+    Div,
+    LParen,
+    RParen,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Symbol(s) | Token::Number(s) => write!(f, "{}", s),
+            Token::Exponent(e) => write!(f, "^{}", e),
+            Token::Root(2) => write!(f, "√"),
+            Token::Root(n) => write!(f, "root{}", n),
+            Token::Mul => write!(f, "·"),
+            Token::Div => write!(f, "/"),
+            Token::LParen => write!(f, "("),
+            Token::RParen => write!(f, ")"),
+        }
+    }
+}
+
+const SUPERSCRIPTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+const FRACTIONS: [(char, i32, i32); 5] = [
+    ('½', 1, 2),
+    ('⅓', 1, 3),
+    ('⅔', 2, 3),
+    ('¼', 1, 4),
+    ('¾', 3, 4),
+];
+
+fn fraction(c: Option<&char>) -> Option<Exponent> {
+    let c = c?;
+    FRACTIONS
+        .iter()
+        .find(|(f, _, _)| f == c)
+        .and_then(|(_, n, d)| Exponent::new(*n, *d))
+}
+
+fn is_symbol_char(c: char) -> bool {
+    !(c.is_whitespace()
+        || c.is_ascii_digit()
+        || SUPERSCRIPTS.contains(&c)
+        || fraction(Some(&c)).is_some()
+        || "*·⋅×/^()-+⁻⁺√∛".contains(c))
+}
+
+fn tokenize(expr: &str) -> Result<Vec<(Token, usize)>, ParseError> {
+    let chars: Vec<char> = expr.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+        match c {
+            _ if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '*' if chars.get(i + 1) == Some(&'*') => {
+                i = exponent(&chars, i + 2, &mut tokens)?;
+                continue;
+            }
+            '^' => {
+                i = exponent(&chars, i + 1, &mut tokens)?;
+                continue;
+            }
+            '*' | '·' | '⋅' | '×' => tokens.push((Token::Mul, start)),
+            '/' => tokens.push((Token::Div, start)),
+            '√' => tokens.push((Token::Root(2), start)),
+            '∛' => tokens.push((Token::Root(3), start)),
+            '(' => tokens.push((Token::LParen, start)),
+            ')' => tokens.push((Token::RParen, start)),
+            _ if c.is_ascii_digit() => {
+                while i < chars.len() && chars[i].is_ascii_digit() {
+                    i += 1;
+                }
+                let number: String = chars[start..i].iter().collect();
+                tokens.push((Token::Number(number), start));
+                continue;
+            }
+            _ if is_symbol_char(c) => {
+                while i < chars.len() && is_symbol_char(chars[i]) {
+                    i += 1;
+                }
+                let symbol: String = chars[start..i].iter().collect();
+                tokens.push((Token::Symbol(symbol), start));
+                // Exponents may directly follow a symbol: m2, m-2, m², m½
+                let signed = matches!(chars.get(i), Some('-' | '+'))
+                    && chars.get(i + 1).is_some_and(|c| c.is_ascii_digit());
+                if signed
+                    || chars.get(i).is_some_and(|c| c.is_ascii_digit())
+                    || fraction(chars.get(i)).is_some()
+                {
+                    i = exponent(&chars, i, &mut tokens)?;
+                }
+                continue;
+            }
+            // Superscript exponents after a symbol or a closing parenthesis: m², (m/s)⁻¹
+            _ if SUPERSCRIPTS.contains(&c) || c == '⁻' || c == '⁺' => {
+                i = superscript(&chars, i, &mut tokens)?;
+                continue;
+            }
+            _ => {
+                return Err(ParseError::UnexpectedToken {
+                    token: c.to_string(),
+                    position: start,
+                })
+            }
+        }
+        i += 1;
+    }
+    Ok(tokens)
+}
+
+// Parses an ASCII exponent starting at `i` (spaces allowed after ^): "-2", "0.5", "(1/2)", "-½".
+fn exponent(
+    chars: &[char],
+    mut i: usize,
+    tokens: &mut Vec<(Token, usize)>,
+) -> Result<usize, ParseError> {
+    while i < chars.len() && chars[i].is_whitespace() {
+        i += 1;
+    }
+    let start = i;
+    let unexpected = |i: usize| match chars.get(i) {
+        Some(c) => ParseError::UnexpectedToken {
+            token: c.to_string(),
+            position: i,
+        },
+        None => ParseError::UnexpectedEnd { position: i },
+    };
+    if chars.get(i) == Some(&'(') {
+        // Parenthesized fraction: ^(1/2), ^(-2/3)
+        let (numerator, next) = integer(chars, i + 1).ok_or_else(|| unexpected(i + 1))?;
+        i = next;
+        let mut denominator = 1;
+        if chars.get(i) == Some(&'/') {
+            let (d, next) = integer(chars, i + 1).ok_or_else(|| unexpected(i + 1))?;
+            if d == 0 {
+                return Err(unexpected(i + 1));
+            }
+            denominator = d;
+            i = next;
+        }
+        if chars.get(i) != Some(&')') {
+            return Err(unexpected(i));
+        }
+        let exponent = Exponent::new(numerator, denominator)
+            .ok_or(ParseError::ExponentOutOfRange { position: start })?;
+        tokens.push((Token::Exponent(exponent), start));
+        return Ok(i + 1);
+    }
+    let sign = match chars.get(i) {
+        Some('-') => -1,
+        _ => 1,
+    };
+    if matches!(chars.get(i), Some('-' | '+')) {
+        i += 1;
+    }
+    if let Some(f) = fraction(chars.get(i)) {
+        tokens.push((Token::Exponent(f * Exponent::integer(sign)), start));
+        return Ok(i + 1);
+    }
+    let digits = i;
+    while i < chars.len() && chars[i].is_ascii_digit() {
+        i += 1;
+    }
+    if digits == i {
+        return Err(unexpected(i));
+    }
+    let mut numerator: i32 = 0;
+    let mut denominator: i32 = 1;
+    let mut end = i;
+    // Decimal exponents are read exactly: 0.5 = 1/2, 0.25 = 1/4
+    if chars.get(i) == Some(&'.') && chars.get(i + 1).is_some_and(|c| c.is_ascii_digit()) {
+        end = i + 1;
+        while end < chars.len() && chars[end].is_ascii_digit() {
+            end += 1;
+        }
+    }
+    for c in chars[digits..end].iter().filter(|c| **c != '.') {
+        let digit = c.to_digit(10).unwrap() as i32;
+        numerator = numerator
+            .checked_mul(10)
+            .and_then(|n| n.checked_add(digit))
+            .ok_or_else(|| ParseError::UnexpectedToken {
+                token: chars[start..end].iter().collect(),
+                position: start,
+            })?;
+    }
+    let out_of_range = ParseError::ExponentOutOfRange { position: start };
+    if end > i {
+        denominator = 10_i32
+            .checked_pow((end - i - 1) as u32)
+            .ok_or(out_of_range.clone())?;
+    }
+    let exponent = Exponent::new(sign * numerator, denominator).ok_or(out_of_range)?;
+    tokens.push((Token::Exponent(exponent), start));
+    Ok(end)
+}
+
+// Reads an optionally signed integer at `i`, returning it and the index after it.
+fn integer(chars: &[char], mut i: usize) -> Option<(i32, usize)> {
+    let start = i;
+    if matches!(chars.get(i), Some('-' | '+')) {
+        i += 1;
+    }
+    let digits = i;
+    while i < chars.len() && chars[i].is_ascii_digit() {
+        i += 1;
+    }
+    if digits == i {
+        return None;
+    }
+    let text: String = chars[start..i].iter().collect();
+    text.parse().ok().map(|n| (n, i))
+}
+
+// Parses a superscript exponent: ², ⁻¹, ⁻¹ᐟ² or ⁻½
+fn superscript(
+    chars: &[char],
+    mut i: usize,
+    tokens: &mut Vec<(Token, usize)>,
+) -> Result<usize, ParseError> {
+    let start = i;
+    let mut sign = 1;
+    if matches!(chars[i], '⁻' | '⁺') {
+        sign = if chars[i] == '⁻' { -1 } else { 1 };
+        i += 1;
+    }
+    if let Some(f) = fraction(chars.get(i)) {
+        tokens.push((Token::Exponent(f * Exponent::integer(sign)), start));
+        return Ok(i + 1);
+    }
+    let read = |i: &mut usize| {
+        let digits = *i;
+        let mut value: Option<i32> = Some(0);
+        while let Some(digit) = chars
+            .get(*i)
+            .and_then(|c| SUPERSCRIPTS.iter().position(|s| s == c))
+        {
+            value = value
+                .and_then(|v| v.checked_mul(10))
+                .and_then(|v| v.checked_add(digit as i32));
+            *i += 1;
+        }
+        (digits != *i).then_some(value)
+    };
+    let out_of_range = ParseError::ExponentOutOfRange { position: start };
+    let unexpected = |i: usize| ParseError::UnexpectedToken {
+        token: chars.get(i).unwrap_or(&chars[start]).to_string(),
+        position: i,
+    };
+    let numerator = read(&mut i)
+        .ok_or_else(|| unexpected(start))?
+        .ok_or(out_of_range.clone())?;
+    let mut denominator = 1;
+    if chars.get(i) == Some(&'ᐟ') {
+        i += 1;
+        denominator = read(&mut i)
+            .ok_or_else(|| unexpected(i))?
+            .ok_or(out_of_range.clone())?;
+        if denominator == 0 {
+            return Err(unexpected(i - 1));
+        }
+    }
+    let exponent = Exponent::new(sign * numerator, denominator).ok_or(out_of_range)?;
+    tokens.push((Token::Exponent(exponent), start));
+    Ok(i)
+}
+
+// ---------- parser ----------
+struct Parser<'a> {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    end: usize,
+    units: &'a [Unit],
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(t, _)| t)
+    }
+
+    fn expr(&mut self) -> Result<Vec<(Unit, Exponent)>, ParseError> {
+        let mut components = self.term()?;
+        loop {
+            let sign = match self.peek() {
+                Some(Token::Mul) => {
+                    self.pos += 1;
+                    1
+                }
+                Some(Token::Div) => {
+                    self.pos += 1;
+                    -1
+                }
+                Some(Token::Symbol(_) | Token::Number(_) | Token::LParen | Token::Root(_)) => 1,
+                _ => return Ok(components),
+            };
+            let term = self.term()?;
+            let sign = Exponent::integer(sign);
+            components.extend(term.into_iter().map(|(u, e)| (u, e * sign)));
+        }
+    }
+
+    fn term(&mut self) -> Result<Vec<(Unit, Exponent)>, ParseError> {
+        let mut components = self.factor()?;
+        if let Some((Token::Exponent(exp), position)) = self.tokens.get(self.pos) {
+            let (exp, position) = (*exp, *position);
+            self.pos += 1;
+            for (_, e) in components.iter_mut() {
+                *e = e
+                    .checked_mul(exp)
+                    .ok_or(ParseError::ExponentOutOfRange { position })?;
+            }
+        }
+        Ok(components)
+    }
+
+    fn factor(&mut self) -> Result<Vec<(Unit, Exponent)>, ParseError> {
+        let Some((token, position)) = self.tokens.get(self.pos).cloned() else {
+            return Err(ParseError::UnexpectedEnd { position: self.end });
+        };
+        self.pos += 1;
+        match token {
+            Token::Symbol(symbol) => match resolve(&symbol, self.units) {
+                Some(unit) => Ok(vec![(unit, Exponent::integer(1))]),
+                None if LOG_UNITS.iter().any(|u| u.symbol == symbol) => {
+                    Err(ParseError::LogarithmicUnit {
+                        token: symbol,
+                        position,
+                    })
+                }
+                None => Err(ParseError::UnknownUnit {
+                    token: symbol,
+                    position,
+                }),
+            },
+            Token::Number(number) if number == "1" => Ok(vec![(ONE, Exponent::integer(1))]),
+            Token::Root(n) => {
+                let out_of_range = ParseError::ExponentOutOfRange { position };
+                let root = Exponent::new(1, n).ok_or(out_of_range.clone())?;
+                self.factor()?
+                    .into_iter()
+                    .map(|(u, e)| Ok((u, e.checked_mul(root).ok_or(out_of_range.clone())?)))
+                    .collect()
+            }
+            Token::LParen => {
+                let components = self.expr()?;
+                match self.tokens.get(self.pos) {
+                    Some((Token::RParen, _)) => {
+                        self.pos += 1;
+                        Ok(components)
+                    }
+                    Some((token, position)) => Err(ParseError::UnexpectedToken {
+                        token: token.to_string(),
+                        position: *position,
+                    }),
+                    None => Err(ParseError::UnexpectedEnd { position: self.end }),
+                }
+            }
+            token => Err(ParseError::UnexpectedToken {
+                token: token.to_string(),
+                position,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oversized_exponents_are_errors() {
+        assert_eq!(
+            parse_unit("m⁹⁹⁹⁹⁹⁹⁹⁹⁹⁹⁹").unwrap_err(),
+            ParseError::ExponentOutOfRange { position: 1 }
+        );
+        assert_eq!(
+            parse_unit("(m^100000)^100000").unwrap_err(),
+            ParseError::ExponentOutOfRange { position: 11 }
+        );
+        assert_eq!(
+            parse_value("2 m⁹⁹⁹⁹⁹⁹⁹⁹⁹⁹⁹").unwrap_err(),
+            ParseError::ExponentOutOfRange { position: 3 }
+        );
+    }
+
+    #[test]
+    fn exponents_at_the_edge_of_the_i32_range_do_not_panic() {
+        let out_of_range =
+            |expr: &str| matches!(parse_unit(expr), Err(ParseError::ExponentOutOfRange { .. }));
+        assert!(out_of_range("m^0.0000000001"));
+        assert!(out_of_range("m^(1/-2147483648)"));
+        assert!(out_of_range(&format!("{}m", "√".repeat(35))));
+        assert!(out_of_range("m^2000000000 m^2000000000"));
+        assert!(out_of_range("(m^(-2147483648))^-1"));
+
+        let min = parse_unit("m^(-2147483648)").unwrap();
+        assert_eq!(min.quantity.dimension[0].numerator(), i32::MIN);
+        let _ = min.to_string();
+        let _ = min.quantity.to_string();
+        let root = parse_unit(&format!("{}m", "√".repeat(30))).unwrap();
+        assert_eq!(
+            root.quantity.dimension[0],
+            Exponent::new(1, 1 << 30).unwrap()
+        );
+    }
+
+    #[test]
+    fn typed_parsing_checks_the_kind() {
+        use crate::gemini::units::{
+            Becquerel, Byte, BytePerSecond, Hertz, Joule, Lumen, NewtonMeter, Radian,
+            RadianPerSecond, RevolutionPerMinute,
+        };
+        let kind_mismatch = |error: ParseError| matches!(error, ParseError::KindMismatch { .. });
+        // With the angle-dimension feature, angular velocity and frequency differ in dimension too
+        let angle_mismatch = |error: ParseError| match error {
+            ParseError::DimensionMismatch { .. } => cfg!(feature = "angle-dimension"),
+            ParseError::KindMismatch { .. } => !cfg!(feature = "angle-dimension"),
+            _ => false,
+        };
+
+        assert!(angle_mismatch(parse_typed::<Hertz>("60 rpm").unwrap_err()));
+        assert!(angle_mismatch(
+            "60 rpm".parse::<gemini::Value<f64, Hertz>>().unwrap_err()
+        ));
+        assert!(angle_mismatch(
+            parse_typed::<RadianPerSecond>("5 Hz").unwrap_err()
+        ));
+        assert!(kind_mismatch(parse_typed::<Becquerel>("5 Hz").unwrap_err()));
+        assert!(kind_mismatch(parse_typed::<Hertz>("5 Bq").unwrap_err()));
+        assert_eq!(
+            parse_typed::<NewtonMeter>("12 J").unwrap_err(),
+            ParseError::KindMismatch {
+                expected: Some("torque"),
+                found: None
+            }
+        );
+
+        let speed: gemini::Value<f64, RevolutionPerMinute> = "60 rpm".parse().unwrap();
+        assert!((speed.value - 60.0).abs() < 1e-9);
+        let speed = parse_typed::<RadianPerSecond>("60 rpm").unwrap();
+        assert!((speed.value - std::f64::consts::TAU).abs() < 1e-9);
+        assert_eq!(parse_typed::<Hertz>("5 kHz").unwrap().value, 5000.0);
+        assert_eq!(parse_typed::<Joule>("12 J").unwrap().value, 12.0);
+        assert_eq!(parse_typed::<Byte>("2 kB").unwrap().value, 2000.0);
+
+        // Written-out products take the kind asked for
+        assert_eq!(parse_typed::<NewtonMeter>("12 N·m").unwrap().value, 12.0);
+        assert_eq!(
+            parse_typed::<RadianPerSecond>("3 rad/s").unwrap().value,
+            3.0
+        );
+        assert_eq!(parse_typed::<BytePerSecond>("3 B/s").unwrap().value, 3.0);
+        assert_eq!(parse_typed::<Hertz>("4 1/s").unwrap().value, 4.0);
+        assert_eq!(parse_typed::<Becquerel>("4 1/s").unwrap().value, 4.0);
+        assert_eq!(parse_typed::<Becquerel>("5 Bq·1").unwrap().value, 5.0);
+
+        // ... but not when a factor is itself of another kind of the same dimension
+        assert_eq!(
+            parse_typed::<Hertz>("5 Bq·1").unwrap_err(),
+            ParseError::KindMismatch {
+                expected: None,
+                found: Some("activity")
+            }
+        );
+        assert_eq!(
+            parse_typed::<NewtonMeter>("5 J·1").unwrap_err(),
+            ParseError::KindMismatch {
+                expected: Some("torque"),
+                found: None
+            }
+        );
+        assert!(kind_mismatch(
+            parse_typed::<Hertz>("5 kBq·m/m").unwrap_err()
+        ));
+
+        // Luminous flux is no luminous intensity, though cd·sr makes a lumen
+        assert!(angle_mismatch(parse_typed::<Lumen>("5 cd").unwrap_err()));
+        assert_eq!(parse_typed::<Lumen>("5 cd·sr").unwrap().value, 5.0);
+        assert!(angle_mismatch(parse_typed::<Radian>("1 sr").unwrap_err()));
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-9 * b.abs().max(1.0)
+    }
+
+    #[test]
+    fn unit_expressions_parse() {
+        let cases: &[(&str, f64, &[i32])] = &[
+            ("kg·m/s^2", 1.0, &[1, 1, -2]),
+            ("kg*m*s**-2", 1.0, &[1, 1, -2]),
+            ("km/h", 1000.0 / 3600.0, &[1, 0, -1]),
+            ("W m-2", 1.0, &[0, 1, -3]),
+            ("J/(mol·K)", 1.0, &[2, 1, -2, 0, -1, -1]),
+            ("m²", 1.0, &[2]),
+            ("s⁻¹", 1.0, &[0, 0, -1]),
+            ("1/ms", 1000.0, &[0, 0, -1]),
+            ("µs", 1e-6, &[0, 0, 1]),
+            ("daN", 10.0, &[1, 1, -2]),
+            ("KiB", 8192.0, &[]),
+            ("Mibit", 1_048_576.0, &[]),
+            ("(m/s)^2", 1.0, &[2, 0, -2]),
+            ("√(m²)", 1.0, &[1]),
+            ("∛L", 0.1, &[1]),
+        ];
+        for (expr, scale, exponents) in cases {
+            let unit = parse_unit(expr).unwrap_or_else(|e| panic!("{}: {}", expr, e));
+            assert!(close(unit.scale(), *scale), "{}: {}", expr, unit.scale());
+            assert_eq!(
+                unit.quantity.dimension,
+                dimension_vector(exponents),
+                "{}",
+                expr
+            );
+        }
+
+        let half = Exponent::HALF;
+        for expr in ["m^(1/2)", "m^0.5", "m½", "√m", "m^½"] {
+            assert_eq!(
+                parse_unit(expr).unwrap().quantity.dimension[0],
+                half,
+                "{}",
+                expr
+            );
+        }
+        let noise = parse_unit("V/√Hz").unwrap();
+        assert_eq!(noise.quantity.dimension[2], Exponent::new(-5, 2).unwrap());
+        assert_eq!("km".parse::<Unit>().unwrap().to_string(), "km");
+    }
+
+    #[test]
+    fn unit_errors_report_what_and_where() {
+        assert_eq!(
+            parse_unit("m/furlong").unwrap_err(),
+            ParseError::UnknownUnit {
+                token: "furlong".to_string(),
+                position: 2
+            }
+        );
+        assert!(matches!(
+            parse_unit("m/"),
+            Err(ParseError::UnexpectedEnd { position: 2 })
+        ));
+        assert!(matches!(
+            parse_unit("(m"),
+            Err(ParseError::UnexpectedEnd { position: 2 })
+        ));
+        assert!(matches!(
+            parse_unit("m)"),
+            Err(ParseError::UnexpectedToken { position: 1, .. })
+        ));
+        assert!(matches!(
+            parse_unit("m^"),
+            Err(ParseError::UnexpectedEnd { position: 2 })
+        ));
+        assert!(matches!(
+            parse_unit("m^(1/0)"),
+            Err(ParseError::UnexpectedToken { position: 5, .. })
+        ));
+        assert_eq!(
+            ParseError::UnknownUnit {
+                token: "x".to_string(),
+                position: 4
+            }
+            .to_string(),
+            "unknown unit 'x' at position 4"
+        );
+
+        // Information units take SI prefixes from kilo up only, and dB is a decibel
+        assert_eq!(parse_unit("kB").unwrap().scale(), 8000.0);
+        assert_eq!(parse_unit("KiB").unwrap().scale(), 8192.0);
+        assert_eq!(
+            parse_unit("dB/s").unwrap_err(),
+            ParseError::LogarithmicUnit {
+                token: "dB".to_string(),
+                position: 0
+            }
+        );
+        assert!(matches!(
+            parse_unit("mB"),
+            Err(ParseError::UnknownUnit { position: 0, .. })
+        ));
+        assert!(matches!(
+            parse_unit("cbit"),
+            Err(ParseError::UnknownUnit { position: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn literals_parse() {
+        let speed = parse_value("5.3 km/h").unwrap();
+        assert_eq!(speed.value, 5.3);
+        assert!(close(speed.to_si(), 5.3 / 3.6));
+        assert_eq!(parse_value("-12e3 mA").unwrap().to_si(), -12.0);
+        assert_eq!(parse_value("1,5 kPa").unwrap().to_si(), 1500.0);
+        assert_eq!(parse_value("  42").unwrap().unit.symbol, "1");
+        assert!(close(parse_value("20 °C").unwrap().to_si(), 293.15));
+        assert_eq!("2 h".parse::<Value>().unwrap().to_si(), 7200.0);
+
+        let grouped = NumberFormat {
+            decimal_separators: &['.'],
+            group_separator: Some(','),
+        };
+        let distance = parse_value_with("1,000.5 m", &grouped, UNITS).unwrap();
+        assert_eq!(distance.value, 1000.5);
+    }
+
+    #[test]
+    fn literal_errors_report_what_and_where() {
+        assert_eq!(
+            parse_value("3 kfurlong").unwrap_err(),
+            ParseError::UnknownUnit {
+                token: "kfurlong".to_string(),
+                position: 2
+            }
+        );
+        assert!(matches!(
+            parse_value("abc m"),
+            Err(ParseError::InvalidNumber { position: 0, .. })
+        ));
+        assert_eq!(
+            parse_value("1..5 m").unwrap_err(),
+            ParseError::InvalidNumber {
+                token: "1..5".to_string(),
+                position: 0,
+                reason: "multiple decimal separators"
+            }
+        );
+        assert_eq!(
+            parse_value(" 1,000.5 m").unwrap_err().to_string(),
+            "invalid number '1,000.5' at position 1: multiple decimal separators"
+        );
+        assert!(matches!(
+            parse_value("1e999 m"),
+            Err(ParseError::InvalidNumber {
+                position: 0,
+                reason: "out of range",
+                ..
+            })
+        ));
+        assert!(parse_value("-1e-999 m").is_ok());
+        let error = parse_typed::<crate::gemini::units::Meter>("3 s").unwrap_err();
+        assert!(matches!(error, ParseError::DimensionMismatch { .. }));
+        assert_eq!(error.to_string(), "expected length [m], found time [s]");
+    }
+}
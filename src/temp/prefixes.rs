@@ -1,31 +1,74 @@
-#[derive(Debug, Clone, Copy)]
+// Which prefixes a unit takes: none (hour, degree), SI (metre) or binary (byte)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrefixSystem {
+    None,
+    SI,
+    Binary,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SIPrefix {
-    Yotta, // 10^24, "Y"
-    Zetta, // 10^21, "Z"
-    Exa,   // 10^18, "E"
-    Peta,  // 10^15, "P"
-    Tera,  // 10^12, "T"
-    Giga,  // 10^9,  "G"
-    Mega,  // 10^6,  "M"
-    Kilo,  // 10^3,  "k"
-    Hecto, // 10^2,  "h"
-    Deca,  // 10^1,  "da"
-    None,  // 10^0,  ""
-    Deci,  // 10^-1, "d"
-    Centi, // 10^-2, "c"
-    Milli, // 10^-3, "m"
-    Micro, // 10^-6, "μ"
-    Nano,  // 10^-9, "n"
-    Pico,  // 10^-12,"p"
-    Femto, // 10^-15,"f"
-    Atto,  // 10^-18,"a"
-    Zepto, // 10^-21,"z"
-    Yocto, // 10^-24,"y"
+    Quetta, // 10^30, "Q"
+    Ronna,  // 10^27, "R"
+    Yotta,  // 10^24, "Y"
+    Zetta,  // 10^21, "Z"
+    Exa,    // 10^18, "E"
+    Peta,   // 10^15, "P"
+    Tera,   // 10^12, "T"
+    Giga,   // 10^9,  "G"
+    Mega,   // 10^6,  "M"
+    Kilo,   // 10^3,  "k"
+    Hecto,  // 10^2,  "h"
+    Deca,   // 10^1,  "da"
+    None,   // 10^0,  ""
+    Deci,   // 10^-1, "d"
+    Centi,  // 10^-2, "c"
+    Milli,  // 10^-3, "m"
+    Micro,  // 10^-6, "μ"
+    Nano,   // 10^-9, "n"
+    Pico,   // 10^-12,"p"
+    Femto,  // 10^-15,"f"
+    Atto,   // 10^-18,"a"
+    Zepto,  // 10^-21,"z"
+    Yocto,  // 10^-24,"y"
+    Ronto,  // 10^-27,"r"
+    Quecto, // 10^-30,"q"
 }
 
 impl SIPrefix {
+    // All prefixes from largest to smallest, including None
+    pub const ALL: [SIPrefix; 25] = [
+        SIPrefix::Quetta,
+        SIPrefix::Ronna,
+        SIPrefix::Yotta,
+        SIPrefix::Zetta,
+        SIPrefix::Exa,
+        SIPrefix::Peta,
+        SIPrefix::Tera,
+        SIPrefix::Giga,
+        SIPrefix::Mega,
+        SIPrefix::Kilo,
+        SIPrefix::Hecto,
+        SIPrefix::Deca,
+        SIPrefix::None,
+        SIPrefix::Deci,
+        SIPrefix::Centi,
+        SIPrefix::Milli,
+        SIPrefix::Micro,
+        SIPrefix::Nano,
+        SIPrefix::Pico,
+        SIPrefix::Femto,
+        SIPrefix::Atto,
+        SIPrefix::Zepto,
+        SIPrefix::Yocto,
+        SIPrefix::Ronto,
+        SIPrefix::Quecto,
+    ];
+
     pub fn multiplier(&self) -> f64 {
         match self {
+            SIPrefix::Quetta => 1e30,
+            SIPrefix::Ronna => 1e27,
             SIPrefix::Yotta => 1e24,
             SIPrefix::Zetta => 1e21,
             SIPrefix::Exa => 1e18,
@@ -47,11 +90,15 @@ impl SIPrefix {
             SIPrefix::Atto => 1e-18,
             SIPrefix::Zepto => 1e-21,
             SIPrefix::Yocto => 1e-24,
+            SIPrefix::Ronto => 1e-27,
+            SIPrefix::Quecto => 1e-30,
         }
     }
 
     pub fn abbreviation(&self) -> &'static str {
         match self {
+            SIPrefix::Quetta => "Q",
+            SIPrefix::Ronna => "R",
             SIPrefix::Yotta => "Y",
             SIPrefix::Zetta => "Z",
             SIPrefix::Exa => "E",
@@ -73,6 +120,102 @@ impl SIPrefix {
             SIPrefix::Atto => "a",
             SIPrefix::Zepto => "z",
             SIPrefix::Yocto => "y",
+            SIPrefix::Ronto => "r",
+            SIPrefix::Quecto => "q",
         }
     }
 }
+
+// IEC binary prefixes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryPrefix {
+    Yobi, // 2^80, "Yi"
+    Zebi, // 2^70, "Zi"
+    Exbi, // 2^60, "Ei"
+    Pebi, // 2^50, "Pi"
+    Tebi, // 2^40, "Ti"
+    Gibi, // 2^30, "Gi"
+    Mebi, // 2^20, "Mi"
+    Kibi, // 2^10, "Ki"
+    None, // 2^0,  ""
+}
+
+impl BinaryPrefix {
+    // All prefixes from largest to smallest, including None
+    pub const ALL: [BinaryPrefix; 9] = [
+        BinaryPrefix::Yobi,
+        BinaryPrefix::Zebi,
+        BinaryPrefix::Exbi,
+        BinaryPrefix::Pebi,
+        BinaryPrefix::Tebi,
+        BinaryPrefix::Gibi,
+        BinaryPrefix::Mebi,
+        BinaryPrefix::Kibi,
+        BinaryPrefix::None,
+    ];
+
+    pub fn multiplier(&self) -> f64 {
+        match self {
+            BinaryPrefix::Yobi => 1_208_925_819_614_629_174_706_176.0,
+            BinaryPrefix::Zebi => 1_180_591_620_717_411_303_424.0,
+            BinaryPrefix::Exbi => 1_152_921_504_606_846_976.0,
+            BinaryPrefix::Pebi => 1_125_899_906_842_624.0,
+            BinaryPrefix::Tebi => 1_099_511_627_776.0,
+            BinaryPrefix::Gibi => 1_073_741_824.0,
+            BinaryPrefix::Mebi => 1_048_576.0,
+            BinaryPrefix::Kibi => 1024.0,
+            BinaryPrefix::None => 1.0,
+        }
+    }
+
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            BinaryPrefix::Yobi => "Yi",
+            BinaryPrefix::Zebi => "Zi",
+            BinaryPrefix::Exbi => "Ei",
+            BinaryPrefix::Pebi => "Pi",
+            BinaryPrefix::Tebi => "Ti",
+            BinaryPrefix::Gibi => "Gi",
+            BinaryPrefix::Mebi => "Mi",
+            BinaryPrefix::Kibi => "Ki",
+            BinaryPrefix::None => "",
+        }
+    }
+}
+
+// Prefix of a runtime unit, from either system
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Prefix {
+    SI(SIPrefix),
+    Binary(BinaryPrefix),
+}
+
+impl Prefix {
+    pub const NONE: Prefix = Prefix::SI(SIPrefix::None);
+
+    pub fn multiplier(&self) -> f64 {
+        match self {
+            Prefix::SI(prefix) => prefix.multiplier(),
+            Prefix::Binary(prefix) => prefix.multiplier(),
+        }
+    }
+
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            Prefix::SI(prefix) => prefix.abbreviation(),
+            Prefix::Binary(prefix) => prefix.abbreviation(),
+        }
+    }
+}
+
+impl From<SIPrefix> for Prefix {
+    fn from(prefix: SIPrefix) -> Self {
+        Prefix::SI(prefix)
+    }
+}
+
+impl From<BinaryPrefix> for Prefix {
+    fn from(prefix: BinaryPrefix) -> Self {
+        Prefix::Binary(prefix)
+    }
+}
@@ -1,86 +1,363 @@
 use std::collections::HashMap;
-use std::ops::{Div, Mul};
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
 use std::sync::{LazyLock, Mutex};
 
-pub type DimensionVector = [i32; 7];
+use crate::dim::{self, DimensionDisplay, DERIVED_KIND, DIMENSIONS};
+
+// Rational exponent of a base dimension (V/√Hz has T^½). Kept in lowest terms with a positive
+// denominator, so equal exponents compare and hash equal. Numerator and denominator are i32:
+// the constructor and the checked_ operations return None rather than overflow, the operators
+// panic.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Exponent {
+    numerator: i32,
+    denominator: i32,
+}
+
+impl Exponent {
+    pub const ZERO: Exponent = Exponent::integer(0);
+    pub const HALF: Exponent = Exponent {
+        numerator: 1,
+        denominator: 2,
+    };
+    pub const THIRD: Exponent = Exponent {
+        numerator: 1,
+        denominator: 3,
+    };
+
+    // None for a zero denominator, or if the reduced ratio does not fit (-2³¹/-1)
+    pub const fn new(numerator: i32, denominator: i32) -> Option<Self> {
+        Exponent::reduce(numerator as i128, denominator as i128)
+    }
+
+    // Wide enough for the cross products of two i32 ratios
+    const fn reduce(numerator: i128, denominator: i128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let sign = if denominator < 0 { -1 } else { 1 };
+        let mut a = numerator.unsigned_abs();
+        let mut b = denominator.unsigned_abs();
+        while b != 0 {
+            let r = a % b;
+            a = b;
+            b = r;
+        }
+        let gcd = if a == 0 { 1 } else { a as i128 };
+        let numerator = sign * numerator / gcd;
+        let denominator = sign * denominator / gcd;
+        if numerator < i32::MIN as i128 || numerator > i32::MAX as i128 {
+            return None;
+        }
+        if denominator > i32::MAX as i128 {
+            return None;
+        }
+        Some(Exponent {
+            numerator: numerator as i32,
+            denominator: denominator as i32,
+        })
+    }
+
+    pub const fn integer(n: i32) -> Self {
+        Exponent {
+            numerator: n,
+            denominator: 1,
+        }
+    }
+
+    pub const fn numerator(&self) -> i32 {
+        self.numerator
+    }
+
+    pub const fn denominator(&self) -> i32 {
+        self.denominator
+    }
+
+    pub const fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    pub const fn checked_add(self, rhs: Exponent) -> Option<Exponent> {
+        Exponent::reduce(
+            self.numerator as i128 * rhs.denominator as i128
+                + rhs.numerator as i128 * self.denominator as i128,
+            self.denominator as i128 * rhs.denominator as i128,
+        )
+    }
+
+    pub const fn checked_sub(self, rhs: Exponent) -> Option<Exponent> {
+        Exponent::reduce(
+            self.numerator as i128 * rhs.denominator as i128
+                - rhs.numerator as i128 * self.denominator as i128,
+            self.denominator as i128 * rhs.denominator as i128,
+        )
+    }
+
+    pub const fn checked_mul(self, rhs: Exponent) -> Option<Exponent> {
+        Exponent::reduce(
+            self.numerator as i128 * rhs.numerator as i128,
+            self.denominator as i128 * rhs.denominator as i128,
+        )
+    }
+
+    pub const fn checked_neg(self) -> Option<Exponent> {
+        Exponent::reduce(-(self.numerator as i128), self.denominator as i128)
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    // Unicode superscript form: ² or ⁻¹ᐟ² (the parser reads both back)
+    pub fn superscript(&self) -> String {
+        dim::superscript(self.numerator, self.denominator)
+    }
+}
+
+impl From<i32> for Exponent {
+    fn from(n: i32) -> Self {
+        Exponent::integer(n)
+    }
+}
+
+const OVERFLOW: &str = "dimension exponent out of range";
+
+impl Add for Exponent {
+    type Output = Exponent;
+    fn add(self, rhs: Exponent) -> Exponent {
+        self.checked_add(rhs).expect(OVERFLOW)
+    }
+}
+
+impl Sub for Exponent {
+    type Output = Exponent;
+    fn sub(self, rhs: Exponent) -> Exponent {
+        self.checked_sub(rhs).expect(OVERFLOW)
+    }
+}
+
+impl Mul for Exponent {
+    type Output = Exponent;
+    fn mul(self, rhs: Exponent) -> Exponent {
+        self.checked_mul(rhs).expect(OVERFLOW)
+    }
+}
+
+impl Neg for Exponent {
+    type Output = Exponent;
+    fn neg(self) -> Exponent {
+        self.checked_neg().expect(OVERFLOW)
+    }
+}
+
+impl fmt::Display for Exponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+// Same as Display, so dimension vectors print as [1, 1/2, 0, ...]
+impl fmt::Debug for Exponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+// L, M, T, I, Θ, N, J, plane angle, then the application-defined lanes (see dim::EXTRA_DIMENSIONS)
+pub type DimensionVector = [Exponent; DIMENSIONS];
+
+// Dimension vector with integer exponents, e.g. dimension_vector(&[1, 0, -2]); missing trailing
+// exponents are zero
+pub const fn dimension_vector(exponents: &[i32]) -> DimensionVector {
+    assert!(
+        exponents.len() <= DIMENSIONS,
+        "more exponents than dimensions"
+    );
+    let mut dv = [Exponent::ZERO; DIMENSIONS];
+    let mut i = 0;
+    while i < exponents.len() {
+        dv[i] = Exponent::integer(exponents[i]);
+        i += 1;
+    }
+    dv
+}
+
+// Unit vector along one lane, e.g. base_dimension(FIRST_EXTRA_DIMENSION) for an application's first
+// extra base dimension (currency, requests, ...)
+pub const fn base_dimension(lane: usize) -> DimensionVector {
+    assert!(lane < DIMENSIONS, "no such dimension");
+    let mut dv = [Exponent::ZERO; DIMENSIONS];
+    dv[lane] = Exponent::integer(1);
+    dv
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Quantity {
-    pub dimension: DimensionVector, // L, M, T, I, Θ, N, J
-    name: Option<&'static str>,
+    pub dimension: DimensionVector,
+    pub(crate) name: Option<&'static str>,
+    // None for generic quantities, or the name of a kind that shares its dimension with others
+    // but does not convert to them implicitly (torque vs energy, activity vs frequency)
+    pub kind: Option<&'static str>,
 }
 
 impl Quantity {
+    // A generic quantity. The first name given to a dimension is registered for it, later ones
+    // (work next to energy) stay with their quantity.
     pub fn new(dimension: DimensionVector, name: Option<&'static str>) -> Self {
         let mut registry = QUANTITY_REGISTRY.lock().unwrap();
-        let reg_name = registry.get_name(&dimension);
-        if reg_name.is_some() && name.is_some() {
-            panic!(
-                "Conflicting names for quantity: registry has '{}' and parameter is '{}'",
-                reg_name.unwrap(),
-                name.unwrap()
-            );
-        }
-
-        if let Some(n) = name {
-            registry.map.insert(dimension, n);
+        if let Some(name) = name {
+            registry.register(dimension, None, name);
         }
-        let local_name = name.or(reg_name);
         Quantity {
             dimension,
-            name: local_name,
+            name: name.or(registry.get_name(&dimension, None)),
+            kind: None,
         }
     }
 
+    // A kind of its own, named after the kind. Kinds stay out of the registry, so a dimension
+    // can carry any number of them next to its generic name.
+    pub const fn with_kind(self, kind: &'static str) -> Quantity {
+        Quantity {
+            dimension: self.dimension,
+            name: Some(kind),
+            kind: Some(kind),
+        }
+    }
+
+    // Values convert implicitly between quantities of the same dimension and kind
+    pub fn is_convertible_to(&self, other: &Quantity) -> bool {
+        self.dimension == other.dimension && self.kind == other.kind
+    }
+
+    // Explicit reinterpretation: a generic quantity can take a kind and a kind can be dropped,
+    // but one kind never becomes another, except a derived one (as with crate::dim::KindCast)
+    pub fn is_castable_to(&self, other: &Quantity) -> bool {
+        self.dimension == other.dimension
+            && (self.kind.is_none()
+                || self.kind == Some(DERIVED_KIND)
+                || other.kind.is_none()
+                || self.kind == other.kind)
+    }
+
+    // Kind of a product or quotient: generic for generic operands, derived as soon as one has a
+    // kind of its own, so bit/s is no Hz (see crate::dim::KindProduct)
+    fn product_kind(&self, other: &Quantity) -> Option<&'static str> {
+        (self.kind.is_some() || other.kind.is_some()).then_some(DERIVED_KIND)
+    }
+
     pub fn combine(components: &[(Quantity, i32)], name: Option<&'static str>) -> Self {
-        let mut new_dim = [0; 7];
-        for (qty, exp) in components {
-            new_dim.iter_mut().enumerate().for_each(|(i, val)| {
-                *val += qty.dimension[i] * exp;
-            });
+        Quantity::new(Quantity::compose(components, None).dimension, name)
+    }
+
+    // Const counterpart of `combine`, which leaves the registry untouched.
+    pub const fn compose(components: &[(Quantity, i32)], name: Option<&'static str>) -> Self {
+        let mut new_dim = [Exponent::ZERO; DIMENSIONS];
+        let mut c = 0;
+        while c < components.len() {
+            let (qty, exp) = components[c];
+            let mut i = 0;
+            while i < DIMENSIONS {
+                let term = qty.dimension[i].checked_mul(Exponent::integer(exp));
+                new_dim[i] = match term {
+                    Some(term) => match new_dim[i].checked_add(term) {
+                        Some(sum) => sum,
+                        None => panic!("dimension exponent out of range"),
+                    },
+                    None => panic!("dimension exponent out of range"),
+                };
+                i += 1;
+            }
+            c += 1;
+        }
+        Quantity {
+            dimension: new_dim,
+            name,
+            kind: None,
         }
-        Quantity::new(new_dim, name)
     }
 
-    pub fn set_name(&mut self, name: &'static str) {
-        let mut registry = QUANTITY_REGISTRY.lock().unwrap();
-        let reg_name = registry.get_name(&self.dimension);
-        if let Some(existing_name) = reg_name {
-            panic!(
-                "Quantity name already set: registry has '{}'",
-                existing_name
-            );
+    // Rational power of a quantity, e.g. the square root of frequency. Panics if an exponent
+    // leaves the i32 range, see checked_powf.
+    pub fn powf(self, exponent: Exponent) -> Quantity {
+        self.checked_powf(exponent).expect(OVERFLOW)
+    }
+
+    pub fn checked_powf(self, exponent: Exponent) -> Option<Quantity> {
+        let mut dimension = [Exponent::ZERO; DIMENSIONS];
+        for (d, e) in dimension.iter_mut().zip(self.dimension) {
+            *d = e.checked_mul(exponent)?;
         }
+        // A kind survives only the first power, the square of a bit is no bit
+        let kind = match self.kind {
+            _ if exponent == Exponent::ZERO => None,
+            Some(_) if exponent != Exponent::integer(1) => Some(DERIVED_KIND),
+            kind => kind,
+        };
+        Some(Quantity {
+            dimension,
+            name: None,
+            kind,
+        })
+    }
 
+    // Names the quantity, and its dimension and kind unless they already have a name
+    pub fn set_name(&mut self, name: &'static str) {
+        QUANTITY_REGISTRY
+            .lock()
+            .unwrap()
+            .register(self.dimension, self.kind, name);
         self.name = Some(name);
-        registry.map.insert(self.dimension, name);
+    }
+
+    // Formatter for the dimension, e.g. quantity.dimension_display().symbols(Symbols::Dimensions)
+    pub fn dimension_display(&self) -> DimensionDisplay {
+        DimensionDisplay::new(self.dimension.map(|e| (e.numerator(), e.denominator())))
     }
 
     pub fn repr(&mut self) -> String {
-        format!("{} [{:?}]", self.name().unwrap_or(""), self.dimension)
+        match self.name() {
+            Some(name) => format!("{} [{}]", name, self),
+            None => format!("[{}]", self),
+        }
     }
 
+    // The quantity's own name, else the one registered for its dimension and kind, else the
+    // kind itself unless derived
     pub fn name(&mut self) -> Option<&'static str> {
-        let registry = QUANTITY_REGISTRY.lock().unwrap();
-        let reg_name = registry.get_name(&self.dimension);
-        if self.name.is_none() && reg_name.is_some() {
-            self.name = reg_name;
+        if self.name.is_none() {
+            let registry = QUANTITY_REGISTRY.lock().unwrap();
+            self.name = registry
+                .get_name(&self.dimension, self.kind)
+                .or(self.kind.filter(|kind| *kind != DERIVED_KIND));
         }
-
         self.name
     }
 }
 
+// The dimension in SI base units, e.g. m·kg·s⁻²
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.dimension_display())
+    }
+}
+
 impl Mul for Quantity {
     type Output = Quantity;
     fn mul(self, rhs: Quantity) -> Quantity {
-        let mut new_dim = [0; 7];
+        let mut new_dim = [Exponent::ZERO; DIMENSIONS];
         new_dim.iter_mut().enumerate().for_each(|(i, val)| {
             *val = self.dimension[i] + rhs.dimension[i];
         });
         Quantity {
             dimension: new_dim,
             name: None,
+            kind: self.product_kind(&rhs),
         }
     }
 }
@@ -88,19 +365,23 @@ impl Mul for Quantity {
 impl Div for Quantity {
     type Output = Quantity;
     fn div(self, rhs: Quantity) -> Quantity {
-        let mut new_dim = [0; 7];
+        let mut new_dim = [Exponent::ZERO; DIMENSIONS];
         new_dim.iter_mut().enumerate().for_each(|(i, val)| {
             *val = self.dimension[i] - rhs.dimension[i];
         });
         Quantity {
             dimension: new_dim,
             name: None,
+            kind: self.product_kind(&rhs),
         }
     }
 }
 
+// Names by dimension and kind, so a kind (or a derived product of kinds, bit/s) never takes
+// the generic name of its dimension
+#[derive(Default)]
 pub struct QuantityRegistry {
-    pub map: HashMap<DimensionVector, &'static str>,
+    pub map: HashMap<(DimensionVector, Option<&'static str>), &'static str>,
 }
 
 impl QuantityRegistry {
@@ -110,10 +391,124 @@ impl QuantityRegistry {
         }
     }
 
-    pub fn get_name(&self, dim: &DimensionVector) -> Option<&'static str> {
-        self.map.get(dim).copied()
+    pub fn get_name(
+        &self,
+        dim: &DimensionVector,
+        kind: Option<&'static str>,
+    ) -> Option<&'static str> {
+        self.map.get(&(*dim, kind)).copied()
+    }
+
+    // The first name registered for a dimension and kind stays
+    pub fn register(
+        &mut self,
+        dim: DimensionVector,
+        kind: Option<&'static str>,
+        name: &'static str,
+    ) {
+        self.map.entry((dim, kind)).or_insert(name);
     }
 }
 
 pub static QUANTITY_REGISTRY: LazyLock<Mutex<QuantityRegistry>> =
     LazyLock::new(|| Mutex::new(QuantityRegistry::new()));
+
+// Macro to create a const Quantity from other quantities and exponents
+macro_rules! quantity {
+    (
+        name: $name:expr,
+        components: [ $( ($qty:expr, $exp:expr) ),* $(,)? ]
+    ) => {
+        $crate::temp::quantity::Quantity::compose(&[ $( ($qty, $exp) ),* ], $name)
+    };
+    (
+        kind: $kind:expr,
+        components: [ $( ($qty:expr, $exp:expr) ),* $(,)? ]
+    ) => {
+        $crate::temp::quantity::Quantity::compose(&[ $( ($qty, $exp) ),* ], None).with_kind($kind)
+    };
+}
+pub(crate) use quantity;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exponents_are_kept_in_lowest_terms() {
+        assert_eq!(Exponent::new(2, -4), Some(Exponent::new(-1, 2).unwrap()));
+        assert_eq!(Exponent::new(0, 7), Some(Exponent::ZERO));
+        assert_eq!(Exponent::new(1, 2), Some(Exponent::HALF));
+        assert_eq!(
+            Exponent::HALF + Exponent::THIRD,
+            Exponent::new(5, 6).unwrap()
+        );
+        assert_eq!(Exponent::HALF * Exponent::integer(4), Exponent::integer(2));
+        assert_eq!(Exponent::new(-3, 2).unwrap().to_string(), "-3/2");
+    }
+
+    #[test]
+    fn overflowing_exponents_are_none() {
+        assert_eq!(Exponent::new(1, 0), None);
+        assert_eq!(Exponent::new(i32::MIN, -1), None);
+        assert_eq!(Exponent::new(1, i32::MIN), None);
+        assert_eq!(
+            Exponent::new(i32::MIN, i32::MIN),
+            Some(Exponent::integer(1))
+        );
+        let min = Exponent::integer(i32::MIN);
+        assert_eq!(min.checked_neg(), None);
+        assert_eq!(min.checked_add(Exponent::integer(-1)), None);
+        assert_eq!(min.checked_sub(Exponent::integer(1)), None);
+        assert_eq!(min.checked_mul(Exponent::integer(2)), None);
+        assert_eq!(
+            Exponent::new(1, 1 << 30)
+                .unwrap()
+                .checked_mul(Exponent::HALF),
+            None
+        );
+        let length = Quantity::compose(&[], None);
+        assert!(length.checked_powf(min).is_some());
+    }
+
+    #[test]
+    fn registry_names_dimensions() {
+        use crate::dim::FIRST_EXTRA_DIMENSION;
+
+        let mut pixels = base_dimension(FIRST_EXTRA_DIMENSION + 1);
+        pixels[FIRST_EXTRA_DIMENSION + 1] = Exponent::integer(2);
+        let mut area = Quantity::new(pixels, Some("pixel area"));
+        assert_eq!(area.name(), Some("pixel area"));
+        let mut unnamed = Quantity::new(pixels, None);
+        assert_eq!(unnamed.name(), Some("pixel area"));
+        assert_eq!(unnamed.repr(), "pixel area [x1²]");
+
+        let mut side = Quantity::new(base_dimension(FIRST_EXTRA_DIMENSION + 1), None);
+        assert_eq!(side.name(), None);
+        assert_eq!((side * side).name(), Some("pixel area"));
+        // A second name stays with its quantity, the dimension keeps the first
+        let mut texels = Quantity::new(pixels, Some("texels"));
+        assert_eq!(texels.name(), Some("texels"));
+        assert_eq!(Quantity::new(pixels, None).name(), Some("pixel area"));
+
+        // Kinds are named apart from their dimension, derived ones have no name
+        let mut coverage = Quantity::new(pixels, None).with_kind("coverage");
+        assert_eq!(coverage.name(), Some("coverage"));
+        let bit = Quantity::new(dimension_vector(&[]), None).with_kind("information");
+        assert_eq!((bit * side * side).name(), None);
+        let mut derived = bit * side * side;
+        derived.set_name("pixel data");
+        assert_eq!((bit * side * side).name(), Some("pixel data"));
+    }
+
+    #[test]
+    fn kinds_name_themselves() {
+        let frequency = Quantity::new(dimension_vector(&[0, 0, -1]), None);
+        let mut activity = frequency.with_kind("activity");
+        assert_eq!(activity.name(), Some("activity"));
+        assert!(!activity.is_convertible_to(&frequency));
+        assert!(activity.is_castable_to(&frequency));
+        assert!(frequency.is_castable_to(&activity));
+        assert!(!activity.is_castable_to(&frequency.with_kind("angular velocity")));
+    }
+}
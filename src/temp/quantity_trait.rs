@@ -2,11 +2,22 @@ use std::collections::HashMap;
 use std::fmt;
 use std::sync::{Arc, LazyLock, Mutex};
 
-// Dimension encoding
-pub const DIMENSIONS: usize = 7;
+// Dimension encoding, lanes as in crate::dim (SI base dimensions, then application-defined ones)
+pub use crate::dim::DIMENSIONS;
 pub type Dimension = i16;
 pub type DimensionVector = [Dimension; DIMENSIONS];
 
+// Pads a shorter list of exponents with zeros, so SI quantities need not spell out the extra lanes
+pub const fn dimension_vector(exponents: &[Dimension]) -> DimensionVector {
+    let mut dv = [0; DIMENSIONS];
+    let mut i = 0;
+    while i < exponents.len() {
+        dv[i] = exponents[i];
+        i += 1;
+    }
+    dv
+}
+
 // Trait for quantities
 pub trait QuantityTrait: fmt::Debug + Send + Sync {
     fn dimension_vector(&self) -> DimensionVector;
@@ -14,17 +25,18 @@ pub trait QuantityTrait: fmt::Debug + Send + Sync {
 }
 
 // Registry for quantities
-struct Registry {
+#[derive(Default)]
+pub struct Registry {
     map: Mutex<HashMap<DimensionVector, Arc<dyn QuantityTrait>>>,
 }
 
 impl Registry {
-    fn new() -> Self {
+    pub fn new() -> Self {
         Registry {
             map: Mutex::new(HashMap::new()),
         }
     }
-    fn get_or_insert<T: QuantityTrait + 'static>(
+    pub fn get_or_insert<T: QuantityTrait + 'static>(
         &self,
         dv: DimensionVector,
         ctor: impl FnOnce() -> T,
@@ -40,25 +52,28 @@ impl Registry {
     }
 }
 
-static QUANTITY_REGISTRY: LazyLock<Registry> = LazyLock::new(Registry::new);
+pub static QUANTITY_REGISTRY: LazyLock<Registry> = LazyLock::new(Registry::new);
 
 // Macro to define a new quantity type and register it
 #[macro_export]
 macro_rules! define_quantity {
     (
-        name: $name:expr,
+        name: $name:ident,
         dim: [$($d:expr),* $(,)?]
-    ) => {
+    ) => {{
         #[derive(Debug)]
         struct $name;
-        impl $crate::quantity_trait::QuantityTrait for $name {
-            fn dimension_vector(&self) -> $crate::quantity_trait::DimensionVector {
-                [$($d),*]
+        impl $crate::temp::quantity_trait::QuantityTrait for $name {
+            fn dimension_vector(&self) -> $crate::temp::quantity_trait::DimensionVector {
+                $crate::temp::quantity_trait::dimension_vector(&[$($d),*])
             }
             fn name(&self) -> &'static str {
-                $name
+                stringify!($name)
             }
         }
-        $crate::quantity_trait::QUANTITY_REGISTRY.get_or_insert([$($d),*], || $name)
-    };
+        $crate::temp::quantity_trait::QUANTITY_REGISTRY.get_or_insert(
+            $crate::temp::quantity_trait::dimension_vector(&[$($d),*]),
+            || $name,
+        )
+    }};
 }
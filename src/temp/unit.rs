@@ -1,28 +1,247 @@
-use crate::prefixes::SIPrefix;
-use crate::quantity::Quantity;
+use crate::temp::prefixes::{Prefix, PrefixSystem, SIPrefix};
+use crate::temp::quantity::{Exponent, Quantity};
+use crate::temp::units::NAMED_UNITS;
 
+use std::fmt;
 use std::ops::{Div, Mul};
 
 #[derive(Debug, Clone, Copy)]
 pub struct Unit {
     pub quantity: Quantity,
     pub factor: f64, // to SI base unit
-    pub prefix: SIPrefix,
+    pub offset: f64, // zero point of this unit in SI base units (273.15 for °C)
+    pub prefix: Prefix,
     pub name: &'static str,
+    pub symbol: &'static str, // without prefix
+    pub prefix_system: PrefixSystem,
+    pub components: Components, // NONE for named units, the factors of products and quotients
+}
+
+// One factor of a composite unit, e.g. km or ms⁻¹ in km·ms⁻¹. The quantity is the factor's own,
+// so a product still knows which of its factors carry a kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Component {
+    pub prefix: Prefix,
+    pub symbol: &'static str,
+    pub exponent: Exponent,
+    pub quantity: Quantity,
+}
+
+pub const MAX_COMPONENTS: usize = 8;
+
+// Factors of a composite unit, kept inline so that Unit stays Copy. Equal factors are merged
+// (m·m = m²) and cancelled ones dropped (m/m). A product with more distinct factors than
+// MAX_COMPONENTS, or of units without a symbol, is anonymous: it keeps its scale and dimension
+// but has no symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Components {
+    items: [Option<Component>; MAX_COMPONENTS],
+    anonymous: bool,
+}
+
+impl Components {
+    pub const NONE: Components = Components {
+        items: [None; MAX_COMPONENTS],
+        anonymous: false,
+    };
+
+    const ANONYMOUS: Components = Components {
+        items: [None; MAX_COMPONENTS],
+        anonymous: true,
+    };
+
+    pub fn is_empty(&self) -> bool {
+        self.items.iter().all(Option::is_none)
+    }
+
+    pub fn is_anonymous(&self) -> bool {
+        self.anonymous
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Component> {
+        self.items.iter().flatten()
+    }
+
+    fn push(&mut self, component: Component) {
+        if self.anonymous {
+            return;
+        }
+        let same = |c: &Option<Component>| {
+            c.is_some_and(|c| c.prefix == component.prefix && c.symbol == component.symbol)
+        };
+        if let Some(slot) = self.items.iter_mut().find(|c| same(c)) {
+            // A merged exponent out of the i32 range leaves the product without a symbol
+            let Some(exponent) = slot.unwrap().exponent.checked_add(component.exponent) else {
+                *self = Components::ANONYMOUS;
+                return;
+            };
+            *slot = (exponent != Exponent::ZERO).then_some(Component {
+                exponent,
+                ..component
+            });
+        } else if let Some(slot) = self.items.iter_mut().find(|c| c.is_none()) {
+            *slot = Some(component);
+        } else {
+            *self = Components::ANONYMOUS;
+        }
+    }
+
+    // Components of the product of the given units raised to their exponents
+    pub fn of(factors: &[(Unit, Exponent)]) -> Components {
+        let mut components = Components::NONE;
+        for (unit, exponent) in factors {
+            if unit.components.anonymous || (unit.components.is_empty() && unit.symbol.is_empty()) {
+                return Components::ANONYMOUS;
+            }
+            if unit.components.is_empty() {
+                // The dimensionless one disappears from products: 1/s is s⁻¹
+                if unit.symbol != "1" {
+                    components.push(Component {
+                        prefix: unit.prefix,
+                        symbol: unit.symbol,
+                        exponent: *exponent,
+                        quantity: unit.quantity,
+                    });
+                }
+            } else {
+                for component in unit.components.iter() {
+                    let Some(exponent) = component.exponent.checked_mul(*exponent) else {
+                        return Components::ANONYMOUS;
+                    };
+                    components.push(Component {
+                        exponent,
+                        ..*component
+                    });
+                }
+            }
+        }
+        components
+    }
 }
 
 impl Unit {
     pub fn to_si(&self, value: f64) -> f64 {
-        value * self.prefix.multiplier() * self.factor
+        value * self.prefix.multiplier() * self.factor + self.offset
+    }
+
+    pub fn from_si(&self, value: f64) -> f64 {
+        (value - self.offset) / (self.prefix.multiplier() * self.factor)
+    }
+
+    // Rational power of a unit (m^½); the prefix is folded into the factor. Panics if a dimension
+    // exponent leaves the i32 range, see checked_powf.
+    pub fn powf(self, exponent: Exponent) -> Unit {
+        self.checked_powf(exponent)
+            .expect("dimension exponent out of range")
+    }
+
+    pub fn checked_powf(self, exponent: Exponent) -> Option<Unit> {
+        Some(Unit {
+            quantity: self.quantity.checked_powf(exponent)?,
+            factor: self.scale().powf(exponent.to_f64()),
+            offset: 0.0,
+            prefix: Prefix::NONE,
+            name: "power_unit",
+            symbol: "",
+            prefix_system: PrefixSystem::None,
+            components: Components::of(&[(self, exponent)]),
+        })
+    }
+
+    // Size of the unit in SI base units, prefix included
+    pub fn scale(&self) -> f64 {
+        self.prefix.multiplier() * self.factor
+    }
+
+    pub fn sqrt(self) -> Unit {
+        self.powf(Exponent::HALF)
+    }
+
+    pub fn cbrt(self) -> Unit {
+        self.powf(Exponent::THIRD)
+    }
+
+    // Resolves a product or quotient to the named unit of the same dimension and kind, with the
+    // SI prefix that keeps its scale: V·A -> W, kg·m²·s⁻³ -> W, N·m -> J (N·m as a torque stays
+    // N·m). Returned unchanged if no named unit fits, which includes every product of units with
+    // a kind: bit/s and rad/s are derived, neither Hz nor a data rate or angular velocity.
+    pub fn simplify(self) -> Unit {
+        if self.offset != 0.0 {
+            return self;
+        }
+        let scale = self.scale();
+        NAMED_UNITS
+            .iter()
+            .filter(|named| named.quantity.is_convertible_to(&self.quantity))
+            .find_map(|named| {
+                let ratio = scale / named.factor;
+                SIPrefix::ALL
+                    .iter()
+                    .filter(|p| match named.prefix_system {
+                        PrefixSystem::None => **p == SIPrefix::None,
+                        // kJ or MW, but no hJ for a scale of 100
+                        _ => p.multiplier().log10().round() as i32 % 3 == 0,
+                    })
+                    .find(|p| (p.multiplier() / ratio - 1.0).abs() < 1e-9)
+                    .map(|p| Unit {
+                        prefix: Prefix::SI(*p),
+                        ..*named
+                    })
+            })
+            .unwrap_or(self)
     }
 
     pub fn repr(&mut self) -> String {
-        format!(
-            "{} ({}{})",
-            self.name,
-            self.prefix.abbreviation(),
-            self.quantity.repr()
-        )
+        let quantity = self.quantity.repr();
+        format!("{} {} ({})", self.name, self, quantity)
+    }
+}
+
+// Symbol with its prefix (km), or the product of the components (km·ms⁻¹). Anonymous units
+// print nothing.
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.components.is_empty() {
+            if self.components.is_anonymous() {
+                return Ok(());
+            }
+            return write!(f, "{}{}", self.prefix.abbreviation(), self.symbol);
+        }
+        let factors: Vec<String> = self
+            .components
+            .iter()
+            .map(|c| {
+                let exponent = if c.exponent == Exponent::integer(1) {
+                    String::new()
+                } else {
+                    c.exponent.superscript()
+                };
+                format!("{}{}{}", c.prefix.abbreviation(), c.symbol, exponent)
+            })
+            .collect();
+        write!(f, "{}", factors.join("·"))
+    }
+}
+
+// Logarithmic unit: level = multiplier * log_base(value / reference), e.g. dBm = 10 * log10(P / 1 mW)
+#[derive(Debug, Clone, Copy)]
+pub struct LogUnit {
+    pub linear: Unit,   // unit of the linear value and the reference
+    pub reference: f64, // in the linear unit
+    pub base: f64,
+    pub multiplier: f64,
+    pub name: &'static str,
+    pub symbol: &'static str,
+}
+
+impl LogUnit {
+    pub fn to_si(&self, level: f64) -> f64 {
+        self.linear
+            .to_si(self.reference * self.base.powf(level / self.multiplier))
+    }
+
+    pub fn from_si(&self, value: f64) -> f64 {
+        self.multiplier * (self.linear.from_si(value) / self.reference).log(self.base)
     }
 }
 
@@ -31,10 +250,15 @@ impl Mul for Unit {
     fn mul(self, rhs: Unit) -> Unit {
         Unit {
             quantity: self.quantity * rhs.quantity,
-            factor: self.factor * rhs.factor,
-            prefix: SIPrefix::None,
+            factor: self.scale() * rhs.scale(),
+            offset: 0.0,
+            prefix: Prefix::NONE,
             name: "product_unit",
+            symbol: "",
+            prefix_system: PrefixSystem::None,
+            components: Components::of(&[(self, 1.into()), (rhs, 1.into())]),
         }
+        .simplify()
     }
 }
 
@@ -43,21 +267,26 @@ impl Div for Unit {
     fn div(self, rhs: Unit) -> Unit {
         Unit {
             quantity: self.quantity / rhs.quantity,
-            factor: self.factor / rhs.factor,
-            prefix: SIPrefix::None,
+            factor: self.scale() / rhs.scale(),
+            offset: 0.0,
+            prefix: Prefix::NONE,
             name: "quotient_unit",
+            symbol: "",
+            prefix_system: PrefixSystem::None,
+            components: Components::of(&[(self, 1.into()), (rhs, (-1).into())]),
         }
+        .simplify()
     }
 }
 
 // Macro to create a const Quantity from base quantities and exponents
-#[macro_export]
 macro_rules! unit {
     (
         name: $name:expr,
         components: [ $( ($unt:expr, $exp:expr) ),* ],
         factor: $factor:expr,
-        prefix: $prefix:expr
+        prefix: $prefix:expr,
+        symbol: $symbol:expr
     ) => {
         Unit {
             quantity: quantity! {
@@ -65,9 +294,107 @@ macro_rules! unit {
                 components: [ $( ($unt.quantity, $exp) ),* ]
             },
             factor: $factor,
+            offset: 0.0,
             prefix: $prefix,
             name: $name,
+            symbol: $symbol,
+            prefix_system: PrefixSystem::SI,
+            components: $crate::temp::unit::Components::NONE,
+        }
+    };
+    (
+        name: $name:expr,
+        quantity: $quantity:expr,
+        factor: $factor:expr,
+        symbol: $symbol:expr
+    ) => {
+        Unit {
+            quantity: $quantity,
+            factor: $factor,
+            offset: 0.0,
+            prefix: Prefix::NONE,
+            name: $name,
+            symbol: $symbol,
+            prefix_system: PrefixSystem::SI,
+            components: $crate::temp::unit::Components::NONE,
         }
     };
 }
 pub(crate) use unit;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dim::DERIVED_KIND;
+    use crate::temp::units::{
+        AMPERE, BIT, DECIBEL_MILLIWATT, DECIBEL_SPL, HERTZ, HOUR, JOULE, KILOGRAM, METER, NEWTON,
+        NEWTON_METER, PH, RADIAN, SECOND, VOLT,
+    };
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-9 * b.abs().max(1.0)
+    }
+
+    #[test]
+    fn log_units_round_trip() {
+        assert!(close(DECIBEL_MILLIWATT.to_si(0.0), 1e-3));
+        assert!(close(DECIBEL_MILLIWATT.to_si(30.0), 1.0));
+        assert!(close(DECIBEL_MILLIWATT.from_si(2.0), 33.010_299_956_639_81));
+        assert!(close(DECIBEL_SPL.from_si(20e-6), 0.0));
+        assert!(close(DECIBEL_SPL.from_si(2.0), 100.0));
+        assert!(close(PH.to_si(7.0), 1e-4));
+        assert!(close(PH.from_si(1e-4), 7.0));
+    }
+
+    fn prefixed(prefix: SIPrefix, unit: Unit) -> Unit {
+        Unit {
+            prefix: Prefix::SI(prefix),
+            ..unit
+        }
+    }
+
+    #[test]
+    fn products_simplify_to_named_units() {
+        let power = VOLT * AMPERE;
+        assert_eq!((power.name, power.to_string()), ("watt", "W".to_string()));
+
+        let power = KILOGRAM * METER * METER / (SECOND * SECOND * SECOND);
+        assert_eq!(power.to_string(), "W");
+
+        let work = prefixed(SIPrefix::Kilo, NEWTON) * METER;
+        assert_eq!(work.to_string(), "kJ");
+        assert_eq!(work.scale(), 1000.0);
+
+        // Torque is a kind of its own, a generic product never becomes one
+        assert_eq!((NEWTON * METER).name, "joule");
+        assert_eq!(NEWTON_METER.simplify().to_string(), "N·m");
+
+        // No named unit fits: the product keeps its components
+        let speed = prefixed(SIPrefix::Kilo, METER) / HOUR;
+        assert_eq!(speed.to_string(), "km·h⁻¹");
+        assert!(close(speed.scale(), 1000.0 / 3600.0));
+        // A scale of 100 J gets no hJ
+        let work = prefixed(SIPrefix::Hecto, NEWTON) * METER;
+        assert_eq!(work.to_string(), "hN·m");
+        assert_eq!(JOULE.simplify().to_string(), "J");
+
+        // Products of kinds keep their factors, whether or not angles are dimensionless
+        assert_eq!((BIT / SECOND).to_string(), "bit·s⁻¹");
+        assert_eq!((BIT / SECOND).quantity.kind, Some(DERIVED_KIND));
+        assert_eq!((RADIAN / SECOND).to_string(), "rad·s⁻¹");
+        assert_eq!((HERTZ * RADIAN).to_string(), "Hz·rad");
+    }
+
+    #[test]
+    fn components_merge_and_cancel() {
+        assert_eq!((METER * METER).to_string(), "m²");
+        assert_eq!((METER / METER).to_string(), "1");
+        assert_eq!(METER.sqrt().to_string(), "m¹ᐟ²");
+        assert_eq!(SECOND.powf(Exponent::integer(-2)).to_string(), "s⁻²");
+        assert!(METER.checked_powf(Exponent::integer(i32::MIN)).is_some());
+        assert!(METER
+            .powf(Exponent::integer(i32::MIN))
+            .checked_powf(Exponent::integer(2))
+            .is_none());
+    }
+}
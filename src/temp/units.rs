@@ -1,60 +1,1008 @@
-use crate::{
-    prefixes::SIPrefix,
-    quantity::{quantity, Quantity},
-    unit::{unit, Unit},
+use crate::dim::{self, Dimension};
+use crate::temp::{
+    prefixes::{Prefix, PrefixSystem, SIPrefix},
+    quantity::{dimension_vector, quantity, Quantity},
+    unit::{unit, Components, LogUnit, Unit},
+};
+
+// Dimensionless
+pub const DIMENSIONLESS: Quantity = Quantity {
+    dimension: dimension_vector(&[0, 0, 0, 0, 0, 0, 0]),
+    name: Some("dimensionless"),
+    kind: None,
+};
+
+pub const ONE: Unit = Unit {
+    quantity: DIMENSIONLESS,
+    factor: 1.0,
+    offset: 0.0,
+    prefix: Prefix::NONE,
+    name: "one",
+    symbol: "1",
+    prefix_system: PrefixSystem::None,
+    components: Components::NONE,
 };
 
 // Base quantities
-pub const LENGTH: Quantity = Quantity::new([1, 0, 0, 0, 0, 0, 0], Some("length"));
+pub const LENGTH: Quantity = Quantity {
+    dimension: dimension_vector(&[1, 0, 0, 0, 0, 0, 0]),
+    name: Some("length"),
+    kind: None,
+};
 pub const MASS: Quantity = Quantity {
-    dimension: [0, 1, 0, 0, 0, 0, 0],
+    dimension: dimension_vector(&[0, 1, 0, 0, 0, 0, 0]),
     name: Some("mass"),
+    kind: None,
 };
 pub const TIME: Quantity = Quantity {
-    dimension: [0, 0, 1, 0, 0, 0, 0],
+    dimension: dimension_vector(&[0, 0, 1, 0, 0, 0, 0]),
     name: Some("time"),
+    kind: None,
+};
+pub const ELECTRIC_CURRENT: Quantity = Quantity {
+    dimension: dimension_vector(&[0, 0, 0, 1, 0, 0, 0]),
+    name: Some("electric current"),
+    kind: None,
+};
+pub const TEMPERATURE: Quantity = Quantity {
+    dimension: dimension_vector(&[0, 0, 0, 0, 1, 0, 0]),
+    name: Some("temperature"),
+    kind: None,
+};
+pub const AMOUNT_OF_SUBSTANCE: Quantity = Quantity {
+    dimension: dimension_vector(&[0, 0, 0, 0, 0, 1, 0]),
+    name: Some("amount of substance"),
+    kind: None,
+};
+pub const LUMINOUS_INTENSITY: Quantity = Quantity {
+    dimension: dimension_vector(&[0, 0, 0, 0, 0, 0, 1]),
+    name: Some("luminous intensity"),
+    kind: None,
 };
 
 // Base units
 pub const METER: Unit = Unit {
     quantity: LENGTH,
     factor: 1.0,
-    prefix: SIPrefix::None,
+    offset: 0.0,
+    prefix: Prefix::NONE,
     name: "meter",
+    symbol: "m",
+    prefix_system: PrefixSystem::SI,
+    components: Components::NONE,
 };
 
-pub const KILOGRAM: Unit = Unit {
+pub const GRAM: Unit = Unit {
     quantity: MASS,
-    factor: 1.0,
-    prefix: SIPrefix::Kilo,
+    factor: 0.001,
+    offset: 0.0,
+    prefix: Prefix::NONE,
     name: "gram",
+    symbol: "g",
+    prefix_system: PrefixSystem::SI,
+    components: Components::NONE,
+};
+
+pub const KILOGRAM: Unit = Unit {
+    prefix: Prefix::SI(SIPrefix::Kilo),
+    ..GRAM
 };
 
 pub const SECOND: Unit = Unit {
     quantity: TIME,
     factor: 1.0,
-    prefix: SIPrefix::None,
+    offset: 0.0,
+    prefix: Prefix::NONE,
     name: "second",
+    symbol: "s",
+    prefix_system: PrefixSystem::SI,
+    components: Components::NONE,
+};
+
+pub const MINUTE: Unit = Unit {
+    quantity: TIME,
+    factor: 60.0,
+    offset: 0.0,
+    prefix: Prefix::NONE,
+    name: "minute",
+    symbol: "min",
+    prefix_system: PrefixSystem::None,
+    components: Components::NONE,
+};
+
+pub const HOUR: Unit = Unit {
+    quantity: TIME,
+    factor: 3600.0,
+    offset: 0.0,
+    prefix: Prefix::NONE,
+    name: "hour",
+    symbol: "h",
+    prefix_system: PrefixSystem::None,
+    components: Components::NONE,
+};
+
+pub const AMPERE: Unit = unit! {
+    name: "ampere",
+    quantity: ELECTRIC_CURRENT,
+    factor: 1.0,
+    symbol: "A"
+};
+
+pub const KELVIN: Unit = unit! {
+    name: "kelvin",
+    quantity: TEMPERATURE,
+    factor: 1.0,
+    symbol: "K"
+};
+
+pub const MOLE: Unit = unit! {
+    name: "mole",
+    quantity: AMOUNT_OF_SUBSTANCE,
+    factor: 1.0,
+    symbol: "mol"
+};
+
+pub const CANDELA: Unit = unit! {
+    name: "candela",
+    quantity: LUMINOUS_INTENSITY,
+    factor: 1.0,
+    symbol: "cd"
+};
+
+// Affine temperature units: 0 °C = 273.15 K, 0 °F = 459.67 °R = 255.372 K
+pub const CELSIUS: Unit = Unit {
+    quantity: TEMPERATURE,
+    factor: 1.0,
+    offset: 273.15,
+    prefix: Prefix::NONE,
+    name: "celsius",
+    symbol: "°C",
+    prefix_system: PrefixSystem::None,
+    components: Components::NONE,
+};
+
+pub const FAHRENHEIT: Unit = Unit {
+    quantity: TEMPERATURE,
+    factor: 5.0 / 9.0,
+    offset: 459.67 * 5.0 / 9.0,
+    prefix: Prefix::NONE,
+    name: "fahrenheit",
+    symbol: "°F",
+    prefix_system: PrefixSystem::None,
+    components: Components::NONE,
+};
+
+// Derived quantities
+pub const AREA: Quantity = quantity! {
+    name: Some("area"),
+    components: [(LENGTH, 2)]
+};
+pub const VOLUME: Quantity = quantity! {
+    name: Some("volume"),
+    components: [(LENGTH, 3)]
+};
+pub const VELOCITY: Quantity = quantity! {
+    name: Some("velocity"),
+    components: [(LENGTH, 1), (TIME, -1)]
+};
+pub const ACCELERATION: Quantity = quantity! {
+    name: Some("acceleration"),
+    components: [(LENGTH, 1), (TIME, -2)]
+};
+pub const CONCENTRATION: Quantity = quantity! {
+    name: Some("concentration"),
+    components: [(AMOUNT_OF_SUBSTANCE, 1), (LENGTH, -3)]
+};
+
+pub const LITER: Unit = unit! {
+    name: "liter",
+    quantity: VOLUME,
+    factor: 1e-3,
+    symbol: "L"
+};
+
+// Plane and solid angle: dimensionless as in the SI, or a base dimension of their own with the
+// angle-dimension feature (see dim::base::A). Either way both are kinds of their own, so rad/s
+// does not pass for Hz, nor sr for rad or rad for the dimensionless one.
+pub const ANGLE: Quantity = Quantity {
+    dimension: dimension_vector(&<dim::base::A as Dimension>::EXPONENTS),
+    name: Some("angle"),
+    kind: Some("angle"),
+};
+pub const SOLID_ANGLE: Quantity = quantity! {
+    kind: "solid angle",
+    components: [(ANGLE, 2)]
+};
+
+pub const RADIAN: Unit = unit! {
+    name: "radian",
+    quantity: ANGLE,
+    factor: 1.0,
+    symbol: "rad"
+};
+
+pub const STERADIAN: Unit = unit! {
+    name: "steradian",
+    quantity: SOLID_ANGLE,
+    factor: 1.0,
+    symbol: "sr"
+};
+
+pub const DEGREE: Unit = Unit {
+    factor: std::f64::consts::PI / 180.0,
+    name: "degree",
+    symbol: "°",
+    prefix_system: PrefixSystem::None,
+    ..RADIAN
+};
+
+pub const GRADIAN: Unit = Unit {
+    factor: std::f64::consts::PI / 200.0,
+    name: "gradian",
+    symbol: "gon",
+    prefix_system: PrefixSystem::None,
+    ..RADIAN
+};
+
+pub const ARCMINUTE: Unit = Unit {
+    factor: std::f64::consts::PI / 10_800.0,
+    name: "arcminute",
+    symbol: "′",
+    prefix_system: PrefixSystem::None,
+    ..RADIAN
+};
+
+// Milli- and microarcseconds are common in astrometry, so this one takes prefixes
+pub const ARCSECOND: Unit = Unit {
+    factor: std::f64::consts::PI / 648_000.0,
+    name: "arcsecond",
+    symbol: "″",
+    ..RADIAN
+};
+
+pub const TURN: Unit = Unit {
+    factor: std::f64::consts::TAU,
+    name: "turn",
+    symbol: "tr",
+    prefix_system: PrefixSystem::None,
+    ..RADIAN
+};
+
+pub const ANGULAR_VELOCITY: Quantity = quantity! {
+    kind: "angular velocity",
+    components: [(ANGLE, 1), (TIME, -1)]
+};
+
+pub const RADIAN_PER_SECOND: Unit = unit! {
+    name: "radian per second",
+    quantity: ANGULAR_VELOCITY,
+    factor: 1.0,
+    symbol: "rad/s"
+};
+
+pub const REVOLUTION_PER_MINUTE: Unit = Unit {
+    factor: std::f64::consts::TAU / 60.0,
+    name: "revolution per minute",
+    symbol: "rpm",
+    prefix_system: PrefixSystem::None,
+    ..RADIAN_PER_SECOND
+};
+
+// Derived quantities with special names and their coherent units. Activity and equivalent dose
+// are kinds of their own, so Bq and Sv do not convert to Hz and Gy implicitly.
+pub const FREQUENCY: Quantity = quantity! {
+    name: Some("frequency"),
+    components: [(TIME, -1)]
+};
+pub const HERTZ: Unit = unit! {
+    name: "hertz",
+    quantity: FREQUENCY,
+    factor: 1.0,
+    symbol: "Hz"
 };
 
-// Force = mass * length / time^2
 pub const FORCE: Quantity = quantity! {
     name: Some("force"),
     components: [(MASS, 1), (LENGTH, 1), (TIME, -2)]
 };
-
-// Newton: base unit for force
 pub const NEWTON: Unit = unit! {
     name: "newton",
-    components: [(KILOGRAM, 1), (METER, 1), (SECOND, -2)],
+    quantity: FORCE,
     factor: 1.0,
-    prefix: SIPrefix::None
+    symbol: "N"
 };
 
-// Horsepower: 1 hp = 745.7 newton-meter/second
-pub const HORSEPOWER: Unit = Unit {
+pub const PRESSURE: Quantity = quantity! {
+    name: Some("pressure"),
+    components: [(FORCE, 1), (AREA, -1)]
+};
+pub const PASCAL: Unit = unit! {
+    name: "pascal",
+    quantity: PRESSURE,
+    factor: 1.0,
+    symbol: "Pa"
+};
+
+pub const ENERGY: Quantity = quantity! {
+    name: Some("energy"),
+    components: [(FORCE, 1), (LENGTH, 1)]
+};
+pub const JOULE: Unit = unit! {
+    name: "joule",
+    quantity: ENERGY,
+    factor: 1.0,
+    symbol: "J"
+};
+
+// Same dimension as energy, but a kind of its own. Not in UNITS: "N·m" always parses as a
+// generic product, use Value::as_kind to read it as a torque.
+pub const TORQUE: Quantity = quantity! {
+    kind: "torque",
+    components: [(FORCE, 1), (LENGTH, 1)]
+};
+pub const NEWTON_METER: Unit = unit! {
+    name: "newton meter",
+    quantity: TORQUE,
+    factor: 1.0,
+    symbol: "N·m"
+};
+
+pub const POWER: Quantity = quantity! {
+    name: Some("power"),
+    components: [(ENERGY, 1), (TIME, -1)]
+};
+pub const WATT: Unit = unit! {
+    name: "watt",
+    quantity: POWER,
+    factor: 1.0,
+    symbol: "W"
+};
+
+pub const ELECTRIC_CHARGE: Quantity = quantity! {
+    name: Some("electric charge"),
+    components: [(ELECTRIC_CURRENT, 1), (TIME, 1)]
+};
+pub const COULOMB: Unit = unit! {
+    name: "coulomb",
+    quantity: ELECTRIC_CHARGE,
+    factor: 1.0,
+    symbol: "C"
+};
+
+pub const VOLTAGE: Quantity = quantity! {
+    name: Some("voltage"),
+    components: [(POWER, 1), (ELECTRIC_CURRENT, -1)]
+};
+pub const VOLT: Unit = unit! {
+    name: "volt",
+    quantity: VOLTAGE,
+    factor: 1.0,
+    symbol: "V"
+};
+
+pub const CAPACITANCE: Quantity = quantity! {
+    name: Some("capacitance"),
+    components: [(ELECTRIC_CHARGE, 1), (VOLTAGE, -1)]
+};
+pub const FARAD: Unit = unit! {
+    name: "farad",
+    quantity: CAPACITANCE,
+    factor: 1.0,
+    symbol: "F"
+};
+
+pub const RESISTANCE: Quantity = quantity! {
+    name: Some("resistance"),
+    components: [(VOLTAGE, 1), (ELECTRIC_CURRENT, -1)]
+};
+pub const OHM: Unit = unit! {
+    name: "ohm",
+    quantity: RESISTANCE,
+    factor: 1.0,
+    symbol: "Ω"
+};
+
+pub const CONDUCTANCE: Quantity = quantity! {
+    name: Some("conductance"),
+    components: [(RESISTANCE, -1)]
+};
+pub const SIEMENS: Unit = unit! {
+    name: "siemens",
+    quantity: CONDUCTANCE,
+    factor: 1.0,
+    symbol: "S"
+};
+
+pub const MAGNETIC_FLUX: Quantity = quantity! {
+    name: Some("magnetic flux"),
+    components: [(VOLTAGE, 1), (TIME, 1)]
+};
+pub const WEBER: Unit = unit! {
+    name: "weber",
+    quantity: MAGNETIC_FLUX,
+    factor: 1.0,
+    symbol: "Wb"
+};
+
+pub const MAGNETIC_FLUX_DENSITY: Quantity = quantity! {
+    name: Some("magnetic flux density"),
+    components: [(MAGNETIC_FLUX, 1), (AREA, -1)]
+};
+pub const TESLA: Unit = unit! {
+    name: "tesla",
+    quantity: MAGNETIC_FLUX_DENSITY,
+    factor: 1.0,
+    symbol: "T"
+};
+
+pub const INDUCTANCE: Quantity = quantity! {
+    name: Some("inductance"),
+    components: [(MAGNETIC_FLUX, 1), (ELECTRIC_CURRENT, -1)]
+};
+pub const HENRY: Unit = unit! {
+    name: "henry",
+    quantity: INDUCTANCE,
+    factor: 1.0,
+    symbol: "H"
+};
+
+// Luminous flux and illuminance are kinds of their own: with dimensionless angles lm is cd·sr
+// = cd and lx = cd/m², the unit of luminance
+pub const LUMINOUS_FLUX: Quantity = quantity! {
+    kind: "luminous flux",
+    components: [(LUMINOUS_INTENSITY, 1), (SOLID_ANGLE, 1)]
+};
+pub const LUMEN: Unit = unit! {
+    name: "lumen",
+    quantity: LUMINOUS_FLUX,
+    factor: 1.0,
+    symbol: "lm"
+};
+
+pub const ILLUMINANCE: Quantity = quantity! {
+    kind: "illuminance",
+    components: [(LUMINOUS_FLUX, 1), (AREA, -1)]
+};
+pub const LUX: Unit = unit! {
+    name: "lux",
+    quantity: ILLUMINANCE,
+    factor: 1.0,
+    symbol: "lx"
+};
+
+pub const ACTIVITY: Quantity = quantity! {
+    kind: "activity",
+    components: [(TIME, -1)]
+};
+pub const BECQUEREL: Unit = unit! {
+    name: "becquerel",
+    quantity: ACTIVITY,
+    factor: 1.0,
+    symbol: "Bq"
+};
+
+pub const ABSORBED_DOSE: Quantity = quantity! {
+    name: Some("absorbed dose"),
+    components: [(ENERGY, 1), (MASS, -1)]
+};
+pub const GRAY: Unit = unit! {
+    name: "gray",
+    quantity: ABSORBED_DOSE,
+    factor: 1.0,
+    symbol: "Gy"
+};
+
+pub const EQUIVALENT_DOSE: Quantity = quantity! {
+    kind: "equivalent dose",
+    components: [(ENERGY, 1), (MASS, -1)]
+};
+pub const SIEVERT: Unit = unit! {
+    name: "sievert",
+    quantity: EQUIVALENT_DOSE,
+    factor: 1.0,
+    symbol: "Sv"
+};
+
+pub const CATALYTIC_ACTIVITY: Quantity = quantity! {
+    name: Some("catalytic activity"),
+    components: [(AMOUNT_OF_SUBSTANCE, 1), (TIME, -1)]
+};
+pub const KATAL: Unit = unit! {
+    name: "katal",
+    quantity: CATALYTIC_ACTIVITY,
+    factor: 1.0,
+    symbol: "kat"
+};
+
+// US customary and Imperial units, exact by definition unless noted
+// (international yard and pound agreement of 1959: 1 yd = 0.9144 m, 1 lb = 0.45359237 kg)
+pub const INCH: Unit = Unit {
+    quantity: LENGTH,
+    factor: 0.0254,
+    offset: 0.0,
+    prefix: Prefix::NONE,
+    name: "inch",
+    symbol: "in",
+    prefix_system: PrefixSystem::None,
+    components: Components::NONE,
+};
+
+pub const FOOT: Unit = Unit {
+    factor: 0.3048,
+    name: "foot",
+    symbol: "ft",
+    ..INCH
+};
+
+pub const YARD: Unit = Unit {
+    factor: 0.9144,
+    name: "yard",
+    symbol: "yd",
+    ..INCH
+};
+
+pub const MILE: Unit = Unit {
+    factor: 1609.344,
+    name: "mile",
+    symbol: "mi",
+    ..INCH
+};
+
+// US survey foot: 1200/3937 m
+pub const SURVEY_FOOT: Unit = Unit {
+    factor: 1200.0 / 3937.0,
+    name: "US survey foot",
+    symbol: "ft_survey",
+    ..INCH
+};
+
+pub const SURVEY_MILE: Unit = Unit {
+    factor: 5280.0 * 1200.0 / 3937.0,
+    name: "US survey mile",
+    symbol: "mi_survey",
+    ..INCH
+};
+
+// US gallon: 231 in³
+pub const GALLON: Unit = Unit {
+    quantity: VOLUME,
+    factor: 3.785411784e-3,
+    offset: 0.0,
+    prefix: Prefix::NONE,
+    name: "US gallon",
+    symbol: "gal",
+    prefix_system: PrefixSystem::None,
+    components: Components::NONE,
+};
+
+pub const QUART: Unit = Unit {
+    factor: 3.785411784e-3 / 4.0,
+    name: "US quart",
+    symbol: "qt",
+    ..GALLON
+};
+
+pub const PINT: Unit = Unit {
+    factor: 3.785411784e-3 / 8.0,
+    name: "US pint",
+    symbol: "pt",
+    ..GALLON
+};
+
+pub const FLUID_OUNCE: Unit = Unit {
+    factor: 3.785411784e-3 / 128.0,
+    name: "US fluid ounce",
+    symbol: "fl_oz",
+    ..GALLON
+};
+
+// Imperial gallon: 4.54609 L
+pub const IMPERIAL_GALLON: Unit = Unit {
+    factor: 4.54609e-3,
+    name: "imperial gallon",
+    symbol: "gal_imp",
+    ..GALLON
+};
+
+pub const IMPERIAL_QUART: Unit = Unit {
+    factor: 4.54609e-3 / 4.0,
+    name: "imperial quart",
+    symbol: "qt_imp",
+    ..GALLON
+};
+
+pub const IMPERIAL_PINT: Unit = Unit {
+    factor: 4.54609e-3 / 8.0,
+    name: "imperial pint",
+    symbol: "pt_imp",
+    ..GALLON
+};
+
+pub const IMPERIAL_FLUID_OUNCE: Unit = Unit {
+    factor: 4.54609e-3 / 160.0,
+    name: "imperial fluid ounce",
+    symbol: "fl_oz_imp",
+    ..GALLON
+};
+
+pub const POUND: Unit = Unit {
+    quantity: MASS,
+    factor: 0.45359237,
+    offset: 0.0,
+    prefix: Prefix::NONE,
+    name: "pound",
+    symbol: "lb",
+    prefix_system: PrefixSystem::None,
+    components: Components::NONE,
+};
+
+pub const OUNCE: Unit = Unit {
+    factor: 0.45359237 / 16.0,
+    name: "ounce",
+    symbol: "oz",
+    ..POUND
+};
+
+pub const STONE: Unit = Unit {
+    factor: 0.45359237 * 14.0,
+    name: "stone",
+    symbol: "st",
+    ..POUND
+};
+
+pub const SHORT_TON: Unit = Unit {
+    factor: 0.45359237 * 2000.0,
+    name: "short ton",
+    symbol: "ton",
+    ..POUND
+};
+
+pub const LONG_TON: Unit = Unit {
+    factor: 0.45359237 * 2240.0,
+    name: "long ton",
+    symbol: "ton_imp",
+    ..POUND
+};
+
+// Slug: the mass accelerated at 1 ft/s² by 1 lbf
+pub const SLUG: Unit = Unit {
+    factor: 0.45359237 * 9.80665 / 0.3048,
+    name: "slug",
+    symbol: "slug",
+    ..POUND
+};
+
+// Pound-force: 1 lb under standard gravity (9.80665 m/s²)
+pub const POUND_FORCE: Unit = Unit {
     quantity: FORCE,
-    factor: 745.7,
-    prefix: SIPrefix::None,
+    factor: 0.45359237 * 9.80665,
+    offset: 0.0,
+    prefix: Prefix::NONE,
+    name: "pound-force",
+    symbol: "lbf",
+    prefix_system: PrefixSystem::None,
+    components: Components::NONE,
+};
+
+pub const PSI: Unit = Unit {
+    quantity: PRESSURE,
+    factor: 0.45359237 * 9.80665 / (0.0254 * 0.0254),
+    offset: 0.0,
+    prefix: Prefix::NONE,
+    name: "pound-force per square inch",
+    symbol: "psi",
+    prefix_system: PrefixSystem::None,
+    components: Components::NONE,
+};
+
+// International Table BTU
+pub const BTU: Unit = Unit {
+    quantity: ENERGY,
+    factor: 1055.05585262,
+    offset: 0.0,
+    prefix: Prefix::NONE,
+    name: "British thermal unit",
+    symbol: "BTU",
+    prefix_system: PrefixSystem::None,
+    components: Components::NONE,
+};
+
+// Mechanical horsepower: 550 ft·lbf/s
+pub const HORSEPOWER: Unit = Unit {
+    quantity: POWER,
+    factor: 550.0 * 0.3048 * 0.45359237 * 9.80665,
+    offset: 0.0,
+    prefix: Prefix::NONE,
     name: "horsepower",
+    symbol: "hp",
+    prefix_system: PrefixSystem::None,
+    components: Components::NONE,
+};
+
+// Metric horsepower: 75 kgf·m/s
+pub const METRIC_HORSEPOWER: Unit = Unit {
+    factor: 75.0 * 9.80665,
+    name: "metric horsepower",
+    symbol: "PS",
+    ..HORSEPOWER
+};
+
+pub const ELECTRICAL_HORSEPOWER: Unit = Unit {
+    factor: 746.0,
+    name: "electrical horsepower",
+    symbol: "hp_E",
+    ..HORSEPOWER
+};
+
+// Information is counted, not measured: a dimensionless kind of its own, so bytes do not convert
+// to radians or to plain ratios
+pub const INFORMATION: Quantity = Quantity {
+    dimension: dimension_vector(&[0, 0, 0, 0, 0, 0, 0]),
+    name: Some("information"),
+    kind: Some("information"),
+};
+
+pub const BIT: Unit = Unit {
+    quantity: INFORMATION,
+    factor: 1.0,
+    offset: 0.0,
+    prefix: Prefix::NONE,
+    name: "bit",
+    symbol: "bit",
+    prefix_system: PrefixSystem::Binary,
+    components: Components::NONE,
+};
+
+pub const NIBBLE: Unit = Unit {
+    factor: 4.0,
+    name: "nibble",
+    symbol: "nibble",
+    prefix_system: PrefixSystem::None,
+    ..BIT
 };
+
+pub const BYTE: Unit = Unit {
+    factor: 8.0,
+    name: "byte",
+    symbol: "B",
+    ..BIT
+};
+
+pub const OCTET: Unit = Unit {
+    factor: 8.0,
+    name: "octet",
+    symbol: "o",
+    ..BIT
+};
+
+// Data rate = information / time; network rates are quoted with SI prefixes (Mbit/s). A kind of
+// its own, apart from frequency.
+pub const DATA_RATE: Quantity = quantity! {
+    kind: "data rate",
+    components: [(INFORMATION, 1), (TIME, -1)]
+};
+
+pub const BIT_PER_SECOND: Unit = unit! {
+    name: "bit per second",
+    quantity: DATA_RATE,
+    factor: 1.0,
+    symbol: "bit/s"
+};
+
+pub const BYTE_PER_SECOND: Unit = Unit {
+    factor: 8.0,
+    name: "byte per second",
+    symbol: "B/s",
+    prefix_system: PrefixSystem::Binary,
+    ..BIT_PER_SECOND
+};
+
+// Units known to the parser, looked up by symbol
+pub const UNITS: &[Unit] = &[
+    ONE,
+    METER,
+    GRAM,
+    SECOND,
+    MINUTE,
+    HOUR,
+    LITER,
+    RADIAN,
+    STERADIAN,
+    DEGREE,
+    GRADIAN,
+    ARCMINUTE,
+    ARCSECOND,
+    TURN,
+    REVOLUTION_PER_MINUTE,
+    AMPERE,
+    KELVIN,
+    CELSIUS,
+    FAHRENHEIT,
+    MOLE,
+    CANDELA,
+    HERTZ,
+    NEWTON,
+    PASCAL,
+    JOULE,
+    WATT,
+    COULOMB,
+    VOLT,
+    FARAD,
+    OHM,
+    SIEMENS,
+    WEBER,
+    TESLA,
+    HENRY,
+    LUMEN,
+    LUX,
+    BECQUEREL,
+    GRAY,
+    SIEVERT,
+    KATAL,
+    INCH,
+    FOOT,
+    YARD,
+    MILE,
+    SURVEY_FOOT,
+    SURVEY_MILE,
+    GALLON,
+    QUART,
+    PINT,
+    FLUID_OUNCE,
+    IMPERIAL_GALLON,
+    IMPERIAL_QUART,
+    IMPERIAL_PINT,
+    IMPERIAL_FLUID_OUNCE,
+    POUND,
+    OUNCE,
+    STONE,
+    SHORT_TON,
+    LONG_TON,
+    SLUG,
+    POUND_FORCE,
+    PSI,
+    BTU,
+    HORSEPOWER,
+    METRIC_HORSEPOWER,
+    ELECTRICAL_HORSEPOWER,
+    BIT,
+    NIBBLE,
+    BYTE,
+    OCTET,
+];
+
+// Units that products and quotients resolve to (see Unit::simplify), matched by dimension and
+// kind, first match wins. Left out are units that would mislabel a product: Gy for m²/s², and lm
+// and lx for cd and cd/m² while the steradian is dimensionless.
+pub const NAMED_UNITS: &[Unit] = &[
+    ONE,
+    METER,
+    GRAM,
+    SECOND,
+    AMPERE,
+    KELVIN,
+    MOLE,
+    CANDELA,
+    HERTZ,
+    NEWTON,
+    PASCAL,
+    JOULE,
+    WATT,
+    COULOMB,
+    VOLT,
+    FARAD,
+    OHM,
+    SIEMENS,
+    WEBER,
+    TESLA,
+    HENRY,
+    KATAL,
+    BECQUEREL,
+    SIEVERT,
+    NEWTON_METER,
+    RADIAN_PER_SECOND,
+];
+
+// Logarithmic ratios
+pub const DECIBEL: LogUnit = LogUnit {
+    linear: ONE,
+    reference: 1.0,
+    base: 10.0,
+    multiplier: 10.0,
+    name: "decibel",
+    symbol: "dB",
+};
+
+pub const NEPER: LogUnit = LogUnit {
+    linear: ONE,
+    reference: 1.0,
+    base: std::f64::consts::E,
+    multiplier: 1.0,
+    name: "neper",
+    symbol: "Np",
+};
+
+// Logarithmic levels of SI quantities
+pub const DECIBEL_MILLIWATT: LogUnit = LogUnit {
+    linear: WATT,
+    reference: 0.001,
+    base: 10.0,
+    multiplier: 10.0,
+    name: "decibel-milliwatt",
+    symbol: "dBm",
+};
+
+pub const DECIBEL_WATT: LogUnit = LogUnit {
+    linear: WATT,
+    reference: 1.0,
+    base: 10.0,
+    multiplier: 10.0,
+    name: "decibel-watt",
+    symbol: "dBW",
+};
+
+pub const DECIBEL_VOLT: LogUnit = LogUnit {
+    linear: VOLT,
+    reference: 1.0,
+    base: 10.0,
+    multiplier: 20.0,
+    name: "decibel-volt",
+    symbol: "dBV",
+};
+
+pub const DECIBEL_SPL: LogUnit = LogUnit {
+    linear: PASCAL,
+    reference: 20e-6,
+    base: 10.0,
+    multiplier: 20.0,
+    name: "decibel sound pressure level",
+    symbol: "dB SPL",
+};
+
+// pH = -log10(c / 1 mol/L)
+pub const PH: LogUnit = LogUnit {
+    linear: unit! {
+        name: "mole per cubic meter",
+        quantity: CONCENTRATION,
+        factor: 1.0,
+        symbol: "mol/m³"
+    },
+    reference: 1000.0,
+    base: 10.0,
+    multiplier: -1.0,
+    name: "pH",
+    symbol: "pH",
+};
+
+// Logarithmic units do not take part in unit expressions, but the parser knows their symbols
+// so that "dB" is not read as a decibyte
+pub const LOG_UNITS: &[LogUnit] = &[
+    DECIBEL,
+    NEPER,
+    DECIBEL_MILLIWATT,
+    DECIBEL_WATT,
+    DECIBEL_VOLT,
+    DECIBEL_SPL,
+    PH,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp::value::Value;
+
+    #[test]
+    fn information_stays_apart_from_other_dimensionless_quantities() {
+        let bytes = Value {
+            value: 1.0,
+            unit: BYTE,
+        };
+        assert_eq!(bytes.convert_to(BIT).unwrap().value, 8.0);
+        assert!(bytes.convert_to(ONE).is_none());
+        assert!(bytes.convert_to(RADIAN).is_none());
+        assert!(!BYTE_PER_SECOND.quantity.is_convertible_to(&HERTZ.quantity));
+        assert!(BYTE_PER_SECOND
+            .quantity
+            .is_convertible_to(&BIT_PER_SECOND.quantity));
+    }
+}
@@ -18,7 +18,8 @@ impl Div for Value {
         }
     }
 }
-use crate::unit::Unit;
+use crate::temp::quantity::Exponent;
+use crate::temp::unit::Unit;
 
 #[derive(Debug, Clone, Copy)]
 pub struct Value {
@@ -31,15 +32,143 @@ impl Value {
         self.unit.to_si(self.value)
     }
 
+    pub fn powf(&self, exponent: Exponent) -> Value {
+        Value {
+            value: self.value.powf(exponent.to_f64()),
+            unit: self.unit.powf(exponent),
+        }
+    }
+
+    pub fn checked_powf(&self, exponent: Exponent) -> Option<Value> {
+        Some(Value {
+            value: self.value.powf(exponent.to_f64()),
+            unit: self.unit.checked_powf(exponent)?,
+        })
+    }
+
+    pub fn sqrt(&self) -> Value {
+        Value {
+            value: self.value.sqrt(),
+            unit: self.unit.sqrt(),
+        }
+    }
+
+    pub fn cbrt(&self) -> Value {
+        Value {
+            value: self.value.cbrt(),
+            unit: self.unit.cbrt(),
+        }
+    }
+
     pub fn convert_to(&self, target: Unit) -> Option<Value> {
-        if self.unit.quantity.dimension != target.quantity.dimension {
+        if !self.unit.quantity.is_convertible_to(&target.quantity) {
             return None;
         }
-        let si_value = self.to_si();
-        let target_value = si_value / target.to_si(1.0);
-        Some(Value {
-            value: target_value,
+        Some(self.reinterpret(target))
+    }
+
+    // Explicit change of kind, e.g. a N·m product as a torque or Bq as plain 1/s
+    pub fn as_kind(&self, target: Unit) -> Option<Value> {
+        if !self.unit.quantity.is_castable_to(&target.quantity) {
+            return None;
+        }
+        Some(self.reinterpret(target))
+    }
+
+    fn reinterpret(&self, target: Unit) -> Value {
+        Value {
+            value: target.from_si(self.to_si()),
             unit: target,
-        })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp::prefixes::{Prefix, SIPrefix};
+    use crate::temp::units::{
+        BECQUEREL, CANDELA, CELSIUS, FAHRENHEIT, HERTZ, HOUR, JOULE, KELVIN, LUMEN, METER, MILE,
+        NEWTON_METER, RADIAN, SECOND, STERADIAN,
+    };
+
+    fn value(value: f64, unit: Unit) -> Value {
+        Value { value, unit }
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-9 * b.abs().max(1.0)
+    }
+
+    #[test]
+    fn conversions_scale_and_shift() {
+        let kilometer = Unit {
+            prefix: Prefix::SI(SIPrefix::Kilo),
+            ..METER
+        };
+        let distance = value(42.195, kilometer);
+        assert!(close(distance.to_si(), 42_195.0));
+        assert!(close(
+            distance.convert_to(MILE).unwrap().value,
+            42_195.0 / 1609.344
+        ));
+        assert!(close(
+            value(1.0, HOUR).convert_to(SECOND).unwrap().value,
+            3600.0
+        ));
+
+        assert!(close(value(100.0, CELSIUS).to_si(), 373.15));
+        assert!(close(
+            value(100.0, CELSIUS).convert_to(FAHRENHEIT).unwrap().value,
+            212.0
+        ));
+        assert!(close(
+            value(-40.0, FAHRENHEIT).convert_to(CELSIUS).unwrap().value,
+            -40.0
+        ));
+        assert!(close(
+            value(0.0, KELVIN).convert_to(CELSIUS).unwrap().value,
+            -273.15
+        ));
+
+        assert!(value(1.0, METER).convert_to(SECOND).is_none());
+    }
+
+    #[test]
+    fn kinds_convert_only_when_cast() {
+        let decay = value(3.0, BECQUEREL);
+        assert!(decay.convert_to(HERTZ).is_none());
+        assert_eq!(decay.as_kind(HERTZ).unwrap().value, 3.0);
+
+        let work = value(12.0, JOULE);
+        assert!(work.convert_to(NEWTON_METER).is_none());
+        assert_eq!(work.as_kind(NEWTON_METER).unwrap().value, 12.0);
+        assert!(value(1.0, NEWTON_METER).as_kind(BECQUEREL).is_none());
+
+        // Both are dimensionless unless angles have a dimension of their own
+        assert!(value(1.0, STERADIAN).convert_to(RADIAN).is_none());
+        assert!(value(1.0, STERADIAN).as_kind(RADIAN).is_none());
+        assert!(value(1.0, LUMEN).convert_to(CANDELA).is_none());
+        let flux = value(2.0, CANDELA) * value(3.0, STERADIAN);
+        assert_eq!(flux.as_kind(LUMEN).unwrap().value, 6.0);
+    }
+
+    #[test]
+    fn products_and_powers_carry_the_unit() {
+        let speed = value(36.0, METER) / value(2.0, SECOND);
+        assert_eq!(speed.value, 18.0);
+        assert_eq!(speed.unit.to_string(), "m·s⁻¹");
+
+        let area = value(3.0, METER).powf(Exponent::integer(2));
+        assert_eq!(area.value, 9.0);
+        assert_eq!(area.unit.to_string(), "m²");
+        assert_eq!(
+            area.sqrt().unit.quantity.dimension,
+            METER.quantity.dimension
+        );
+        assert!(close(area.sqrt().value, 3.0));
+        assert!(value(1.0, METER)
+            .checked_powf(Exponent::integer(i32::MIN))
+            .is_some());
     }
 }
@@ -0,0 +1,3 @@
+pub mod quantity;
+pub mod unit;
+pub mod value;
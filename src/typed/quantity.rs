@@ -1,23 +1,92 @@
 // ================= core type =================
 
-pub const DIMENSIONS: usize = 7;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Div, Mul};
+
+use crate::dim;
+
+pub use crate::dim::DIMENSIONS;
 pub type Dimension = i16;
 pub type DimensionVector = [Dimension; DIMENSIONS];
-pub type EncodedDimensionVector = i64; // cant use unsigned due to overflow problems
+pub type EncodedDimensionVector = i128; // cant use unsigned due to overflow problems
+
+// The dimension is a type (crate::dim::Dim of type-level integers) rather than a const EDV, so
+// products and quotients can be named in generic impls on stable Rust. K tells apart kinds of
+// the same dimension (see crate::dim::Kind); products and quotients are Generic.
+pub struct Quantity<D, K = dim::Generic>(PhantomData<(D, K)>);
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct Quantity<const EDV: EncodedDimensionVector>;
+// Manual impls, the derives would require D itself to implement them
+impl<D, K> Clone for Quantity<D, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<D, K> Copy for Quantity<D, K> {}
+impl<D, K> Default for Quantity<D, K> {
+    fn default() -> Self {
+        Quantity(PhantomData)
+    }
+}
+impl<D: dim::Dimension, K: 'static> fmt::Debug for Quantity<D, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match reg::lookup::<D, K>() {
+            Some(entry) => write!(f, "Quantity({} {:?})", entry.name, D::EXPONENTS),
+            None => write!(f, "Quantity{:?}", D::EXPONENTS),
+        }
+    }
+}
+
+// The registered name, or the dimension in base units for quantities nobody named
+impl<D: dim::Dimension, K: 'static> fmt::Display for Quantity<D, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match reg::lookup::<D, K>() {
+            Some(entry) => write!(f, "{}", entry.name),
+            None => write!(f, "{}", Self::dimension()),
+        }
+    }
+}
+
+impl<D: dim::Dimension, K> Quantity<D, K> {
+    pub fn dimension() -> dim::DimensionDisplay {
+        dim::DimensionDisplay::of::<D>()
+    }
+}
+
+// Expose a type's dimension and kind as types
+pub trait Dimensioned {
+    type Dim: dim::Dimension;
+    type Kind;
+}
+impl<D: dim::Dimension, K> Dimensioned for Quantity<D, K> {
+    type Dim = D;
+    type Kind = K;
+}
 
 // Expose a type's encoded exponents as a single const
 pub trait EncodedDimensions {
     const EDV: EncodedDimensionVector;
 }
-impl<const EDV: EncodedDimensionVector> EncodedDimensions for Quantity<EDV> {
-    const EDV: EncodedDimensionVector = EDV;
+impl<D: dim::Dimension, K> EncodedDimensions for Quantity<D, K> {
+    const EDV: EncodedDimensionVector = enc::pack(enc::from_exponents(D::EXPONENTS));
+}
+
+impl<DA: dim::Product<DB>, DB, KA, KB> Mul<Quantity<DB, KB>> for Quantity<DA, KA> {
+    type Output = Quantity<DA::Output>;
+    fn mul(self, _: Quantity<DB, KB>) -> Self::Output {
+        Quantity(PhantomData)
+    }
+}
+
+impl<DA: dim::Quotient<DB>, DB, KA, KB> Div<Quantity<DB, KB>> for Quantity<DA, KA> {
+    type Output = Quantity<DA::Output>;
+    fn div(self, _: Quantity<DB, KB>) -> Self::Output {
+        Quantity(PhantomData)
+    }
 }
 
 // ---------- packing layout ----------
-// Each dimension is stored in a lane within those 64 bits, with a bias to allow negative dimensionality.
+// Each dimension is stored in a lane within those 128 bits, with a bias to allow negative dimensionality.
 pub mod enc {
     use super::{Dimension, DimensionVector, EncodedDimensionVector, DIMENSIONS};
 
@@ -25,18 +94,57 @@ pub mod enc {
     const MASK: EncodedDimensionVector = (1 << BITS) - 1;
     const BIAS: EncodedDimensionVector = 1 << (BITS - 1);
 
+    // Range of a single exponent: an 11-bit lane holds -1024..=1023. Packing anything outside fails
+    // const evaluation instead of wrapping into a neighbouring lane.
+    pub const MIN_EXPONENT: Dimension = -(BIAS as Dimension);
+    pub const MAX_EXPONENT: Dimension = (BIAS - 1) as Dimension;
+
+    const fn in_range(exponent: i32) -> bool {
+        exponent >= MIN_EXPONENT as i32 && exponent <= MAX_EXPONENT as i32
+    }
+
+    const fn check(exponent: i32) -> Dimension {
+        if !in_range(exponent) {
+            panic!("dimension exponent out of range for the packed encoding (-1024..=1023)");
+        }
+        exponent as Dimension
+    }
+
     #[inline(always)]
     pub const fn pack(e: DimensionVector) -> EncodedDimensionVector {
         let mut edv: EncodedDimensionVector = 0;
         let mut i = 0;
         while i < DIMENSIONS {
-            let v = (e[i] as EncodedDimensionVector) + BIAS;
+            let v = (check(e[i] as i32) as EncodedDimensionVector) + BIAS;
             edv |= (v & MASK) << (i as Dimension * BITS);
             i += 1;
         }
         edv
     }
 
+    // Non-panicking pack for vectors built at run time: None if any exponent is out of range
+    pub const fn try_pack(e: DimensionVector) -> Option<EncodedDimensionVector> {
+        let mut i = 0;
+        while i < DIMENSIONS {
+            if !in_range(e[i] as i32) {
+                return None;
+            }
+            i += 1;
+        }
+        Some(pack(e))
+    }
+
+    // Exponents of a crate::dim::Dimension, narrowed to the lane type
+    pub const fn from_exponents(e: [i32; DIMENSIONS]) -> DimensionVector {
+        let mut dv = [0; DIMENSIONS];
+        let mut i = 0;
+        while i < DIMENSIONS {
+            dv[i] = check(e[i]);
+            i += 1;
+        }
+        dv
+    }
+
     #[inline(always)]
     pub const fn unpack(code: EncodedDimensionVector) -> DimensionVector {
         let mut dv = [0; DIMENSIONS];
@@ -49,6 +157,7 @@ pub mod enc {
         dv
     }
 
+    // Sums and products are formed in i32 so they reach `check` before they can overflow
     #[inline(always)]
     pub const fn add(
         a: EncodedDimensionVector,
@@ -59,7 +168,7 @@ pub mod enc {
         let mut r = [0; DIMENSIONS];
         let mut i = 0;
         while i < DIMENSIONS {
-            r[i] = ea[i] + eb[i];
+            r[i] = check(ea[i] as i32 + eb[i] as i32);
             i += 1;
         }
         pack(r)
@@ -71,63 +180,306 @@ pub mod enc {
         let mut r = [0; DIMENSIONS];
         let mut i = 0;
         while i < DIMENSIONS {
-            r[i] = ea[i] * k;
+            r[i] = check(ea[i] as i32 * k as i32);
             i += 1;
         }
         pack(r)
     }
 
-    pub const ZERO: EncodedDimensionVector = pack([0, 0, 0, 0, 0, 0, 0]);
+    // Formatter for an encoded vector, e.g. display(edv).to_string() == "m·kg·s⁻²"
+    pub fn display(code: EncodedDimensionVector) -> crate::dim::DimensionDisplay {
+        crate::dim::DimensionDisplay::from_integers(unpack(code).map(|e| e as i32))
+    }
+
+    pub const ZERO: EncodedDimensionVector = pack([0; DIMENSIONS]);
+
+    // Round trips, checked at compile time: the extremes and the values around zero survive pack/unpack in every lane, next to both extremes in all other lanes.
+    // (Sweeping all 2048 values per lane exceeds the const evaluation budget; the tests below do.)
+    const PROBES: [Dimension; 7] = [
+        MIN_EXPONENT,
+        MIN_EXPONENT + 1,
+        -1,
+        0,
+        1,
+        MAX_EXPONENT - 1,
+        MAX_EXPONENT,
+    ];
+
+    const _: () = {
+        let mut lane = 0;
+        while lane < DIMENSIONS {
+            let mut fill = 0;
+            while fill < 2 {
+                let other = if fill == 0 {
+                    MIN_EXPONENT
+                } else {
+                    MAX_EXPONENT
+                };
+                let mut p = 0;
+                while p < PROBES.len() {
+                    let mut dv = [other; DIMENSIONS];
+                    dv[lane] = PROBES[p];
+                    let back = unpack(pack(dv));
+                    let mut i = 0;
+                    while i < DIMENSIONS {
+                        assert!(back[i] == dv[i], "pack/unpack round trip failed");
+                        i += 1;
+                    }
+                    p += 1;
+                }
+                fill += 1;
+            }
+            lane += 1;
+        }
+        assert!(
+            add(
+                pack([1, 1, -2, 0, 0, 0, 0, 0, 0, 0, 0]),
+                pack([-1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0])
+            ) == pack([0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+        );
+        assert!(
+            scale(pack([1, 0, -1, 0, 0, 0, 0, 0, 0, 0, 0]), -3)
+                == pack([-3, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0])
+        );
+    };
 }
 
-// pub mod reg {
-//     use super::EncodedDimensionVector;
-//     use std::collections::HashMap;
-//     use std::sync::LazyLock;
-//     use std::sync::Mutex;
+// ---------- registry ----------
+// Reverse lookup from encoded dimensions (and kind) to a quantity name and its preferred unit,
+// so values can print as "9.81 N" rather than a bare exponent vector. The coherent SI quantities
+// are built in; register your own with reg::register.
+pub mod reg {
+    use std::any::TypeId;
+    use std::collections::HashMap;
+    use std::sync::{LazyLock, Mutex};
 
-//     static QUANTITY_REGISTRY: LazyLock<Mutex<HashMap<EncodedDimensionVector, &'static str>>> =
-//         LazyLock::new(|| Mutex::new(HashMap::new()));
-// }
+    use super::{enc, Dimension, Dimensioned, EncodedDimensionVector, DIMENSIONS};
+    use crate::dim;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Entry {
+        pub name: &'static str,
+        pub symbol: &'static str, // preferred unit, in which values are displayed
+    }
+
+    const fn edv(exponents: &[Dimension]) -> EncodedDimensionVector {
+        let mut dv = [0; DIMENSIONS];
+        let mut i = 0;
+        while i < exponents.len() {
+            dv[i] = exponents[i];
+            i += 1;
+        }
+        enc::pack(dv)
+    }
+
+    const fn entry(name: &'static str, symbol: &'static str) -> Entry {
+        Entry { name, symbol }
+    }
+
+    // Exponents of L, M, T, I, Θ, N, J. Lumen and lux are left out: with a dimensionless
+    // steradian they coincide with candela and luminance.
+    const BUILTIN: &[(EncodedDimensionVector, Entry)] = &[
+        (edv(&[]), entry("dimensionless", "")),
+        (edv(&[1]), entry("length", "m")),
+        (edv(&[0, 1]), entry("mass", "kg")),
+        (edv(&[0, 0, 1]), entry("time", "s")),
+        (edv(&[0, 0, 0, 1]), entry("electric current", "A")),
+        (edv(&[0, 0, 0, 0, 1]), entry("temperature", "K")),
+        (
+            edv(&[0, 0, 0, 0, 0, 1]),
+            entry("amount of substance", "mol"),
+        ),
+        (
+            edv(&[0, 0, 0, 0, 0, 0, 1]),
+            entry("luminous intensity", "cd"),
+        ),
+        (edv(&[2]), entry("area", "m²")),
+        (edv(&[3]), entry("volume", "m³")),
+        (edv(&[1, 0, -1]), entry("velocity", "m/s")),
+        (edv(&[1, 0, -2]), entry("acceleration", "m/s²")),
+        (edv(&[-3, 0, 0, 0, 0, 1]), entry("concentration", "mol/m³")),
+        (edv(&[0, 0, -1]), entry("frequency", "Hz")),
+        (edv(&[1, 1, -2]), entry("force", "N")),
+        (edv(&[-1, 1, -2]), entry("pressure", "Pa")),
+        (edv(&[2, 1, -2]), entry("energy", "J")),
+        (edv(&[2, 1, -3]), entry("power", "W")),
+        (edv(&[0, 0, 1, 1]), entry("electric charge", "C")),
+        (edv(&[2, 1, -3, -1]), entry("voltage", "V")),
+        (edv(&[-2, -1, 4, 2]), entry("capacitance", "F")),
+        (edv(&[2, 1, -3, -2]), entry("resistance", "Ω")),
+        (edv(&[-2, -1, 3, 2]), entry("conductance", "S")),
+        (edv(&[2, 1, -2, -1]), entry("magnetic flux", "Wb")),
+        (edv(&[0, 1, -2, -1]), entry("magnetic flux density", "T")),
+        (edv(&[2, 1, -2, -2]), entry("inductance", "H")),
+        (edv(&[2, 0, -2]), entry("absorbed dose", "Gy")),
+        (
+            edv(&[0, 0, -1, 0, 0, 1]),
+            entry("catalytic activity", "kat"),
+        ),
+    ];
+
+    // Keyed by the kind's type as well, so torque and energy get entries of their own
+    type Key = (EncodedDimensionVector, TypeId);
+
+    static REGISTRY: LazyLock<Mutex<HashMap<Key, Entry>>> = LazyLock::new(|| {
+        let generic = TypeId::of::<dim::Generic>();
+        Mutex::new(
+            BUILTIN
+                .iter()
+                .map(|(edv, entry)| ((*edv, generic), *entry))
+                .collect(),
+        )
+    });
+
+    // The associated const is evaluated at compile time, so a dimension outside the packed range
+    // fails the build here too rather than panicking on first use.
+    fn key<D: dim::Dimension, K: 'static>() -> Key {
+        (
+            <super::Quantity<D, K> as super::EncodedDimensions>::EDV,
+            TypeId::of::<K>(),
+        )
+    }
+
+    // Names a quantity, replacing any previous entry, e.g. register::<Torque>("torque", "N·m")
+    pub fn register<Q: Dimensioned>(name: &'static str, symbol: &'static str)
+    where
+        Q::Kind: 'static,
+    {
+        REGISTRY
+            .lock()
+            .unwrap()
+            .insert(key::<Q::Dim, Q::Kind>(), Entry { name, symbol });
+    }
+
+    pub fn lookup<D: dim::Dimension, K: 'static>() -> Option<Entry> {
+        REGISTRY.lock().unwrap().get(&key::<D, K>()).copied()
+    }
+}
 
 // ================= macros =================
+#[doc(hidden)]
 #[macro_export]
-macro_rules! quantity {
+macro_rules! __typed_quantity {
     // Dimension vector with named dimensions
-    ( $name:expr, [ L$l:expr, M$m:expr, T$t:expr, I$i:expr, Th$th:expr, N$n:expr, J$j:expr ] ) => {
-        $crate::quantity::Quantity::<{ $crate::quantity::enc::pack([$l, $m, $t, $i, $th, $n, $j]) }>
+    ( [ L$l:expr, M$m:expr, T$t:expr, I$i:expr, Th$th:expr, N$n:expr, J$j:expr ] ) => {
+        $crate::typed::quantity::Quantity<
+            $crate::dim::Dim<
+                <$crate::dim::Exponent<{ $l }> as $crate::dim::ToInteger>::Output,
+                <$crate::dim::Exponent<{ $m }> as $crate::dim::ToInteger>::Output,
+                <$crate::dim::Exponent<{ $t }> as $crate::dim::ToInteger>::Output,
+                <$crate::dim::Exponent<{ $i }> as $crate::dim::ToInteger>::Output,
+                <$crate::dim::Exponent<{ $th }> as $crate::dim::ToInteger>::Output,
+                <$crate::dim::Exponent<{ $n }> as $crate::dim::ToInteger>::Output,
+                <$crate::dim::Exponent<{ $j }> as $crate::dim::ToInteger>::Output,
+            >,
+        >
     };
 
-    // List of (Quantity, Power) pairs
-    ( $name:expr, [ $( ($ty:ty, $pow:expr) ),* $(,)? ] ) => {
-        $crate::quantity::Quantity::<{
-            let mut acc = $crate::quantity::enc::ZERO;
-            $( acc = $crate::quantity::enc::add(acc,  $crate::quantity::enc::scale(<$ty as $crate::quantity::EncodedDimensions>::EDV, $pow)); )*
-            acc
-        }>
+    // A kind of its own with the dimension of the given quantities, e.g.
+    //   quantity!(kind: TorqueKind, [(Force, 1), (Length, 1)])
+    ( kind: $kind:ty, $dims:tt ) => {
+        $crate::typed::quantity::Quantity<
+            <$crate::typed::quantity::quantity!($dims) as $crate::typed::quantity::Dimensioned>::Dim,
+            $kind,
+        >
     };
 
-    // Empty => dimensionless
-    ( [] ) => { $crate::Quantity::<{ $crate::enc::ZERO }> };
+    // List of (Quantity, Power) pairs, dimensionless if empty
+    ( [ $( ($ty:ty, $pow:expr) ),* $(,)? ] ) => {
+        $crate::typed::quantity::Quantity<$crate::typed::quantity::quantity!(@dim $( ($ty, $pow) ),*)>
+    };
+
+    // Product of the quantities' dimensions raised to their powers
+    (@dim) => { $crate::dim::Dimensionless };
+    (@dim ($ty:ty, $pow:expr) $(, ($rest:ty, $rest_pow:expr))*) => {
+        <<<$ty as $crate::typed::quantity::Dimensioned>::Dim as $crate::dim::Power<
+            <$crate::dim::Exponent<{ $pow }> as $crate::dim::ToInteger>::Output,
+        >>::Output as $crate::dim::Product<
+            $crate::typed::quantity::quantity!(@dim $( ($rest, $rest_pow) ),*),
+        >>::Output
+    };
 }
+pub use crate::__typed_quantity as quantity;
+
+#[cfg(test)]
+mod tests {
+    use super::enc::{self, MAX_EXPONENT, MIN_EXPONENT};
+    use super::{quantity, Dimension, Dimensioned, DIMENSIONS};
+
+    #[test]
+    fn every_exponent_round_trips_in_every_lane() {
+        for lane in 0..DIMENSIONS {
+            for other in [MIN_EXPONENT, 0, MAX_EXPONENT] {
+                for exponent in MIN_EXPONENT..=MAX_EXPONENT {
+                    let mut dv = [other; DIMENSIONS];
+                    dv[lane] = exponent;
+                    assert_eq!(enc::unpack(enc::pack(dv)), dv, "lane {lane}");
+                    assert_eq!(enc::try_pack(dv), Some(enc::pack(dv)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn out_of_range_exponents_are_rejected() {
+        for lane in 0..DIMENSIONS {
+            for exponent in [
+                MIN_EXPONENT - 1,
+                MAX_EXPONENT + 1,
+                Dimension::MIN,
+                Dimension::MAX,
+            ] {
+                let mut dv = [0; DIMENSIONS];
+                dv[lane] = exponent;
+                assert_eq!(enc::try_pack(dv), None, "lane {lane}, exponent {exponent}");
+                assert!(std::panic::catch_unwind(|| enc::pack(dv)).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn sums_and_scales_leaving_the_range_are_rejected() {
+        let max = enc::pack([MAX_EXPONENT; DIMENSIONS]);
+        assert!(std::panic::catch_unwind(|| enc::add(max, max)).is_err());
+        assert!(std::panic::catch_unwind(|| enc::scale(max, 2)).is_err());
+        assert_eq!(enc::scale(max, -1), enc::pack([-MAX_EXPONENT; DIMENSIONS]));
+    }
+
+    #[test]
+    fn registry_lookup_tells_kinds_apart() {
+        use super::reg;
+        use crate::dim::{Generic, Kind};
 
-// #[macro_export]
-// macro_rules! quantity2 {
-//     (name: $name:expr, dim: $dim:expr) => {{
-//         let edv = $dim;
-//         {
-//             let mut reg = $crate::quantity::QUANTITY_REGISTRY.lock().unwrap();
-//             reg.insert(edv, $name);
-//         }
-//         Quantity::<{ edv }>
-//     }};
-//     (dim: $dim:expr) => {{
-//         let edv = $dim;
-//         let name = {
-//             let reg = $crate::quantity::QUANTITY_REGISTRY.lock().unwrap();
-//             reg.get(&edv).copied()
-//         };
-//         // You can use the name for display, etc.
-//         Quantity::<{ edv }>
-//     }};
-// }
+        type Energy = quantity!([L 2, M 1, T -2, I 0, Th 0, N 0, J 0]);
+        type Jerk = quantity!([L 1, M 0, T -3, I 0, Th 0, N 0, J 0]);
+        // Same type name in two modules, which a key on type_name would not tell apart
+        mod a {
+            pub struct Moment;
+            impl crate::dim::Kind for Moment {
+                const NAME: &'static str = "torque";
+            }
+        }
+        mod b {
+            pub struct Moment;
+            impl crate::dim::Kind for Moment {
+                const NAME: &'static str = "moment of force";
+            }
+        }
+        type Torque = quantity!(kind: a::Moment, [(Energy, 1)]);
+        type Moment = quantity!(kind: b::Moment, [(Energy, 1)]);
+        type Dimension = <Energy as Dimensioned>::Dim;
+        assert_eq!(<a::Moment as Kind>::NAME, "torque");
+
+        let energy = reg::lookup::<Dimension, Generic>().unwrap();
+        assert_eq!((energy.name, energy.symbol), ("energy", "J"));
+        assert_eq!(Energy::default().to_string(), "energy");
+        assert_eq!(Jerk::default().to_string(), "m·s⁻³");
+        assert_eq!(reg::lookup::<Dimension, a::Moment>(), None);
+
+        reg::register::<Torque>("torque", "N·m");
+        reg::register::<Moment>("moment of force", "N·m");
+        assert_eq!(Torque::default().to_string(), "torque");
+        assert_eq!(Moment::default().to_string(), "moment of force");
+        assert_eq!(Energy::default().to_string(), "energy");
+    }
+}
@@ -1,48 +1,149 @@
 // ================= core type =================
 
-use crate::quantity::{EncodedDimensionVector, EncodedDimensions, Quantity};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Div, Mul};
 
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub struct Unit<const EDV: EncodedDimensionVector> {
-    pub scale: f64, // scale to base unit
+use crate::dim::{self, Integer};
+use crate::typed::quantity::{EncodedDimensionVector, EncodedDimensions, Quantity};
+
+pub struct Unit<D, K = dim::Generic> {
+    pub scale: f64,  // scale to base unit
+    pub offset: f64, // zero point in base unit (273.15 for °C)
     pub symbol: &'static str,
+    _dimension: PhantomData<(D, K)>,
+}
+
+impl<D, K> Unit<D, K> {
+    pub const fn new(scale: f64, offset: f64, symbol: &'static str) -> Self {
+        Unit {
+            scale,
+            offset,
+            symbol,
+            _dimension: PhantomData,
+        }
+    }
+
+    // Integer power of a unit, e.g. METER.powi::<P2>() for m²
+    pub fn powi<E: Integer>(self) -> Unit<<D as dim::Power<E>>::Output>
+    where
+        D: dim::Power<E>,
+    {
+        Unit::new(self.scale.powi(E::VALUE), 0.0, "")
+    }
+
+    // Even exponents only, see dim::SquareRoot; V/√Hz needs the runtime API
+    pub fn sqrt(self) -> Unit<<D as dim::SquareRoot>::Output>
+    where
+        D: dim::SquareRoot,
+    {
+        Unit::new(self.scale.sqrt(), 0.0, "")
+    }
+
+    pub fn cbrt(self) -> Unit<<D as dim::CubeRoot>::Output>
+    where
+        D: dim::CubeRoot,
+    {
+        Unit::new(self.scale.cbrt(), 0.0, "")
+    }
+}
+
+// Manual impls, the derives would require D itself to implement them
+impl<D, K> Clone for Unit<D, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<D, K> Copy for Unit<D, K> {}
+impl<D, K> PartialEq for Unit<D, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.scale == other.scale && self.offset == other.offset && self.symbol == other.symbol
+    }
+}
+impl<D: dim::Dimension, K: 'static> fmt::Debug for Unit<D, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Unit")
+            .field("quantity", &Quantity::<D, K>::default())
+            .field("scale", &self.scale)
+            .field("offset", &self.offset)
+            .field("symbol", &self.symbol)
+            .finish()
+    }
+}
+
+// Products and quotients are coherent with their operands' scales; offsets do not carry over
+// (°C·m is not affine) and the result has no symbol of its own.
+impl<DA: dim::Product<DB>, DB, KA, KB> Mul<Unit<DB, KB>> for Unit<DA, KA> {
+    type Output = Unit<DA::Output>;
+    fn mul(self, rhs: Unit<DB, KB>) -> Self::Output {
+        Unit::new(self.scale * rhs.scale, 0.0, "")
+    }
+}
+
+impl<DA: dim::Quotient<DB>, DB, KA, KB> Div<Unit<DB, KB>> for Unit<DA, KA> {
+    type Output = Unit<DA::Output>;
+    fn div(self, rhs: Unit<DB, KB>) -> Self::Output {
+        Unit::new(self.scale / rhs.scale, 0.0, "")
+    }
 }
 
 // Trait for units
 pub trait EncodedUnit {
     const EDV: EncodedDimensionVector;
     fn scale(&self) -> f64;
+    fn offset(&self) -> f64;
     fn symbol(&self) -> &'static str;
+
+    fn to_base(&self, value: f64) -> f64 {
+        value * self.scale() + self.offset()
+    }
+    fn to_unit(&self, value: f64) -> f64 {
+        (value - self.offset()) / self.scale()
+    }
 }
 
-impl<const EDV: EncodedDimensionVector> EncodedUnit for Unit<EDV> {
-    const EDV: EncodedDimensionVector = EDV;
+impl<D: dim::Dimension, K> EncodedUnit for Unit<D, K> {
+    const EDV: EncodedDimensionVector = <Quantity<D, K> as EncodedDimensions>::EDV;
     fn scale(&self) -> f64 {
         self.scale
     }
+    fn offset(&self) -> f64 {
+        self.offset
+    }
     fn symbol(&self) -> &'static str {
         self.symbol
     }
 }
 
-// Macro to define a new unit type
+// Macro to define a new unit constant
+#[doc(hidden)]
 #[macro_export]
-macro_rules! unit {
+macro_rules! __typed_unit {
     (
 		$name:ident,
 		quantity = $quantity:ty,
 		scale = $scale:expr,
 		symbol = $symbol:expr
 	) => {
-        pub type $name =
-            $crate::unit::Unit<{ <$quantity as $crate::quantity::EncodedDimensions>::EDV }>;
-        impl $name {
-            pub const fn new() -> Self {
-                Self {
-                    scale: $scale,
-                    symbol: $symbol,
-                }
-            }
-        }
+        $crate::typed::unit::unit!(
+            $name,
+            quantity = $quantity,
+            scale = $scale,
+            offset = 0.0,
+            symbol = $symbol
+        );
+    };
+    (
+		$name:ident,
+		quantity = $quantity:ty,
+		scale = $scale:expr,
+		offset = $offset:expr,
+		symbol = $symbol:expr
+	) => {
+        pub const $name: $crate::typed::unit::Unit<
+            <$quantity as $crate::typed::quantity::Dimensioned>::Dim,
+            <$quantity as $crate::typed::quantity::Dimensioned>::Kind,
+        > = $crate::typed::unit::Unit::new($scale, $offset, $symbol);
     };
 }
+pub use crate::__typed_unit as unit;
@@ -0,0 +1,256 @@
+// ================= core type =================
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::dim::{self, Integer};
+use crate::typed::quantity::{reg, Quantity};
+use crate::typed::unit::{EncodedUnit, Unit};
+
+// A magnitude of dimension D and kind K, stored in coherent SI base units
+pub struct Value<D, K = dim::Generic> {
+    pub base: f64,
+    _dimension: PhantomData<(D, K)>,
+}
+
+impl<D, K> Value<D, K> {
+    pub const fn from_base(base: f64) -> Self {
+        Value {
+            base,
+            _dimension: PhantomData,
+        }
+    }
+
+    pub fn powi<E: Integer>(self) -> Value<<D as dim::Power<E>>::Output>
+    where
+        D: dim::Power<E>,
+    {
+        Value::from_base(self.base.powi(E::VALUE))
+    }
+
+    // Halves every exponent, so an odd one (√Hz) does not compile; see dim::SquareRoot
+    pub fn sqrt(self) -> Value<<D as dim::SquareRoot>::Output>
+    where
+        D: dim::SquareRoot,
+    {
+        Value::from_base(self.base.sqrt())
+    }
+
+    pub fn cbrt(self) -> Value<<D as dim::CubeRoot>::Output>
+    where
+        D: dim::CubeRoot,
+    {
+        Value::from_base(self.base.cbrt())
+    }
+
+    // Explicit change of kind, e.g. a generic N·m product read as a torque (see dim::KindCast)
+    pub fn as_kind<KT>(self) -> Value<D, KT>
+    where
+        K: dim::KindCast<KT>,
+    {
+        Value::from_base(self.base)
+    }
+}
+
+impl<D: dim::Dimension, K> Value<D, K> {
+    pub fn new(value: f64, unit: Unit<D, K>) -> Self {
+        Value::from_base(unit.to_base(value))
+    }
+
+    // The value's quantity, which displays as its registered name
+    pub fn quantity(&self) -> Quantity<D, K> {
+        Quantity::default()
+    }
+
+    // The magnitude expressed in the given unit
+    pub fn get(&self, unit: Unit<D, K>) -> f64 {
+        unit.to_unit(self.base)
+    }
+}
+
+// Manual impls, the derives would require D itself to implement them
+impl<D, K> Clone for Value<D, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<D, K> Copy for Value<D, K> {}
+impl<D, K> PartialEq for Value<D, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.base == other.base
+    }
+}
+impl<D, K> PartialOrd for Value<D, K> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.base.partial_cmp(&other.base)
+    }
+}
+impl<D: dim::Dimension, K: 'static> fmt::Debug for Value<D, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match reg::lookup::<D, K>() {
+            Some(entry) => write!(f, "Value({} {}, {})", self.base, entry.symbol, entry.name),
+            None => write!(f, "Value({} {})", self.base, Quantity::<D, K>::dimension()),
+        }
+    }
+}
+
+// In the registered preferred unit, which is coherent, so the base magnitude prints as it is;
+// unnamed quantities print in SI base units
+impl<D: dim::Dimension, K: 'static> fmt::Display for Value<D, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match reg::lookup::<D, K>() {
+            Some(entry) if entry.symbol.is_empty() => write!(f, "{}", self.base),
+            Some(entry) => write!(f, "{} {}", self.base, entry.symbol),
+            None => write!(f, "{} {}", self.base, Quantity::<D, K>::dimension()),
+        }
+    }
+}
+
+// ================= arithmetic =================
+impl<DA: dim::Product<DB>, DB, KA, KB> Mul<Value<DB, KB>> for Value<DA, KA> {
+    type Output = Value<DA::Output>;
+    fn mul(self, rhs: Value<DB, KB>) -> Self::Output {
+        Value::from_base(self.base * rhs.base)
+    }
+}
+
+impl<DA: dim::Quotient<DB>, DB, KA, KB> Div<Value<DB, KB>> for Value<DA, KA> {
+    type Output = Value<DA::Output>;
+    fn div(self, rhs: Value<DB, KB>) -> Self::Output {
+        Value::from_base(self.base / rhs.base)
+    }
+}
+
+impl<D, K> Mul<f64> for Value<D, K> {
+    type Output = Value<D, K>;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Value::from_base(self.base * rhs)
+    }
+}
+
+impl<D, K> Div<f64> for Value<D, K> {
+    type Output = Value<D, K>;
+    fn div(self, rhs: f64) -> Self::Output {
+        Value::from_base(self.base / rhs)
+    }
+}
+
+impl<D, K> Add for Value<D, K> {
+    type Output = Value<D, K>;
+    fn add(self, rhs: Value<D, K>) -> Self::Output {
+        Value::from_base(self.base + rhs.base)
+    }
+}
+
+impl<D, K> Sub for Value<D, K> {
+    type Output = Value<D, K>;
+    fn sub(self, rhs: Value<D, K>) -> Self::Output {
+        Value::from_base(self.base - rhs.base)
+    }
+}
+
+impl<D, K> Neg for Value<D, K> {
+    type Output = Value<D, K>;
+    fn neg(self) -> Self::Output {
+        Value::from_base(-self.base)
+    }
+}
+
+// ================= trigonometry =================
+// Angles are stored in radians like every other base unit
+impl Value<dim::base::A> {
+    pub fn sin(self) -> f64 {
+        self.base.sin()
+    }
+
+    pub fn cos(self) -> f64 {
+        self.base.cos()
+    }
+
+    pub fn tan(self) -> f64 {
+        self.base.tan()
+    }
+
+    pub fn asin(ratio: f64) -> Self {
+        Value::from_base(ratio.asin())
+    }
+
+    pub fn acos(ratio: f64) -> Self {
+        Value::from_base(ratio.acos())
+    }
+
+    pub fn atan(ratio: f64) -> Self {
+        Value::from_base(ratio.atan())
+    }
+}
+
+impl<D, K> Value<D, K> {
+    // Angle of the vector (x, y), with self as the y component
+    pub fn atan2(self, x: Value<D, K>) -> Value<dim::base::A> {
+        Value::from_base(self.base.atan2(x.base))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dim::P2;
+    use crate::typed::quantity::quantity;
+    use crate::typed::unit::unit;
+
+    type Length = quantity!([L 1, M 0, T 0, I 0, Th 0, N 0, J 0]);
+    type Time = quantity!([L 0, M 0, T 1, I 0, Th 0, N 0, J 0]);
+    type Temperature = quantity!([L 0, M 0, T 0, I 0, Th 1, N 0, J 0]);
+    type Velocity = quantity!([(Length, 1), (Time, -1)]);
+    unit!(METER, quantity = Length, scale = 1.0, symbol = "m");
+    unit!(MILE, quantity = Length, scale = 1609.344, symbol = "mi");
+    unit!(HOUR, quantity = Time, scale = 3600.0, symbol = "h");
+    unit!(
+        KILOMETER_PER_HOUR,
+        quantity = Velocity,
+        scale = 1.0 / 3.6,
+        symbol = "km/h"
+    );
+    unit!(KELVIN, quantity = Temperature, scale = 1.0, symbol = "K");
+    unit!(
+        CELSIUS,
+        quantity = Temperature,
+        scale = 1.0,
+        offset = 273.15,
+        symbol = "°C"
+    );
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-9 * b.abs().max(1.0)
+    }
+
+    #[test]
+    fn values_convert_through_the_base_unit() {
+        let distance = Value::new(26.2, MILE);
+        assert!(close(distance.base, 26.2 * 1609.344));
+        assert!(close(distance.get(METER), 42_164.812_8));
+
+        let speed = distance / Value::new(2.0, HOUR);
+        assert!(close(speed.get(KILOMETER_PER_HOUR), 21.082_406_4));
+        assert!(close((speed * Value::new(1.0, HOUR)).get(MILE), 13.1));
+
+        let boiling = Value::new(100.0, CELSIUS);
+        assert!(close(boiling.base, 373.15));
+        assert!(close(boiling.get(KELVIN), 373.15));
+        assert!(close(Value::new(0.0, KELVIN).get(CELSIUS), -273.15));
+    }
+
+    #[test]
+    fn values_display_in_the_registered_unit() {
+        let speed = Value::new(36.0, KILOMETER_PER_HOUR);
+        assert_eq!(speed.to_string(), "10 m/s");
+        assert_eq!(format!("{:?}", speed), "Value(10 m/s, velocity)");
+        assert_eq!(speed.quantity().to_string(), "velocity");
+        let hypervolume = Value::new(2.0, METER).powi::<P2>().powi::<P2>();
+        assert_eq!(hypervolume.to_string(), "16 m⁴");
+        assert_eq!(format!("{:?}", hypervolume), "Value(16 m⁴)");
+        let half = (Value::new(3.0, METER) - Value::new(1.0, METER)) / 4.0;
+        assert_eq!(half.to_string(), "0.5 m");
+    }
+}